@@ -1,10 +1,109 @@
-use std::{env, num::ParseIntError};
+use std::{
+    env,
+    f64::consts::{FRAC_PI_2, FRAC_PI_4, FRAC_PI_8, PI, SQRT_2},
+    num::ParseIntError,
+};
+
+use qubit::{basis::Basis, entangled_pair::EntangledPair};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const A: [f64; 2] = [0.0, FRAC_PI_4];
+const B: [f64; 2] = [FRAC_PI_8, 3.0 * FRAC_PI_8];
+
+/// Estimates `E(a, b)` from `num_pairs` samples of `±1` outcomes, with its standard error.
+fn correlator<F>(num_pairs: u32, mut sample: F) -> (f64, f64)
+where
+    F: FnMut() -> (bool, bool),
+{
+    let num_same = (0..num_pairs)
+        .filter(|_| {
+            let (a, b) = sample();
+            a == b
+        })
+        .count();
+
+    let e = (2 * num_same) as f64 / num_pairs as f64 - 1.0;
+
+    (e, ((1.0 - e * e) / num_pairs as f64).sqrt())
+}
+
+/// Runs the four CHSH settings and prints `S = E(a, b) - E(a, b') + E(a', b) + E(a', b')`.
+fn chsh<F>(name: &str, num_pairs: u32, mut sample: F)
+where
+    F: FnMut(f64, f64) -> (bool, bool),
+{
+    println!("{}:", name);
+
+    let mut s = 0.0;
+    let mut variance = 0.0;
+
+    for (i, a) in A.into_iter().enumerate() {
+        for (j, b) in B.into_iter().enumerate() {
+            let (e, err) = correlator(num_pairs, || sample(a, b));
+
+            println!(
+                "  E({:5.1}°, {:5.1}°) = {:+.3} ± {:.3}",
+                a.to_degrees(),
+                b.to_degrees(),
+                e,
+                err
+            );
+
+            s += if i == 0 && j == 1 { -e } else { e };
+            variance += err * err;
+        }
+    }
+
+    let err = variance.sqrt();
+
+    println!("  S = {:.3} ± {:.3}", s, err);
+    let classical = (s - 2.0) / err;
+    let tsirelson = (s - 2.0 * SQRT_2) / err;
+
+    println!(
+        "  Classical bound 2: {:+.1}σ ({})",
+        classical,
+        if classical > 3.0 {
+            "violated"
+        } else {
+            "respected"
+        }
+    );
+    println!(
+        "  Tsirelson bound 2√2 = {:.3}: {:+.1}σ ({})",
+        2.0 * SQRT_2,
+        tsirelson,
+        if tsirelson > 3.0 {
+            "violated"
+        } else {
+            "respected"
+        }
+    );
+}
 
 fn main() -> Result<(), ParseIntError> {
-    let _num_qubits: u32 = match env::args().nth(1) {
+    let num_qubits: u32 = match env::args().nth(1) {
         Some(s) => s.parse()?,
         None => 100_000,
     };
 
+    let mut rng = match env::args().nth(2) {
+        Some(s) => StdRng::seed_from_u64(s.parse()?),
+        None => StdRng::from_entropy(),
+    };
+
+    chsh("Entangled pairs", num_qubits, |a, b| {
+        EntangledPair::phi_plus().measure(Basis::from_radians(a), Basis::from_radians(b), &mut rng)
+    });
+
+    // Each pair carries a shared hidden polarization, and each analyzer passes it
+    // deterministically if it lies within 45° of the analyzer's axis.
+    chsh("Local hidden variables", num_qubits, |a, b| {
+        let lambda: f64 = rng.gen_range(0.0..PI);
+        let passes = |angle: f64| ((angle - lambda).rem_euclid(PI) - FRAC_PI_2).abs() > FRAC_PI_4;
+
+        (passes(a), passes(b))
+    });
+
     Ok(())
 }
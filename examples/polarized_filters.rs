@@ -2,9 +2,36 @@ use std::{env, num::ParseIntError};
 
 use qubit::{
     basis::{DEG_0, DEG_45, DEG_90},
-    filter::Filter,
+    filter::{Filter, FilterChain},
     qubit::Qubit,
 };
+use rand::{rngs::StdRng, SeedableRng};
+
+fn report(name: &str, chain: &FilterChain) {
+    println!("{}", name);
+
+    for (i, filter) in chain.get_filters().iter().enumerate() {
+        println!(
+            "  Stage {}: {} / {} passed",
+            i, filter.num_passed, filter.num_total
+        );
+    }
+
+    let predicted = chain.get_unpolarized_transmission();
+    let deviation = chain.get_deviation(predicted);
+
+    println!(
+        "  Passed: {:.3}% (predicted: {:.3}%, {:+.1}σ{})",
+        chain.get_transmission() * 100.0,
+        predicted * 100.0,
+        deviation,
+        if deviation.abs() > 3.0 {
+            ", significant"
+        } else {
+            ""
+        }
+    );
+}
 
 fn main() -> Result<(), ParseIntError> {
     let num_qubits: u32 = match env::args().nth(1) {
@@ -12,55 +39,35 @@ fn main() -> Result<(), ParseIntError> {
         None => 100_000,
     };
 
-    let mut filter_0 = Filter::new(DEG_0, true);
-    let mut filter_45 = Filter::new(DEG_45, true);
-    let mut filter_90 = Filter::new(DEG_90, true);
+    // Passing a seed makes the run reproducible
+    let mut rng = match env::args().nth(2) {
+        Some(s) => StdRng::seed_from_u64(s.parse()?),
+        None => StdRng::from_entropy(),
+    };
 
-    let mut qb;
+    let mut chain = FilterChain::new(vec![Filter::new(DEG_0, true), Filter::new(DEG_90, true)]);
 
     for _ in 0..num_qubits {
-        qb = Qubit::random();
+        let qb = Qubit::random(&mut rng);
 
-        qb = if let Some(qb) = filter_0.filter(qb) {
-            qb
-        } else {
-            continue;
-        };
-
-        filter_90.filter(qb);
+        chain.filter(qb, &mut rng);
     }
 
-    println!(
-        "[0 - 90] Passed: {:.3}%",
-        (filter_90.num_passed * 100) as f64 / filter_0.num_total as f64
-    );
+    report("[0 - 90]", &chain);
 
-    filter_0.reset();
-    filter_45.reset();
-    filter_90.reset();
+    let mut chain = FilterChain::new(vec![
+        Filter::new(DEG_0, true),
+        Filter::new(DEG_45, true),
+        Filter::new(DEG_90, true),
+    ]);
 
     for _ in 0..num_qubits {
-        qb = Qubit::random();
-
-        qb = if let Some(qb) = filter_0.filter(qb) {
-            qb
-        } else {
-            continue;
-        };
-
-        qb = if let Some(qb) = filter_45.filter(qb) {
-            qb
-        } else {
-            continue;
-        };
+        let qb = Qubit::random(&mut rng);
 
-        filter_90.filter(qb);
+        chain.filter(qb, &mut rng);
     }
 
-    println!(
-        "[0 - 45 - 90] Passed: {:.3}%",
-        (filter_90.num_passed * 100) as f64 / filter_0.num_total as f64
-    );
+    report("[0 - 45 - 90]", &chain);
 
     Ok(())
 }
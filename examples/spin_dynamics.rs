@@ -0,0 +1,56 @@
+use std::{env, f64::consts::PI, num::ParseIntError};
+
+use num_complex::Complex64;
+use qubit::{
+    basis::{DEG_0, DEG_45},
+    gate::{PAULI_X, PAULI_Z},
+    qubit::Qubit,
+};
+
+const OMEGA: f64 = 1.0;
+const DETUNING: f64 = 0.5;
+
+fn main() -> Result<(), ParseIntError> {
+    let num_steps: u32 = match env::args().nth(1) {
+        Some(s) => s.parse()?,
+        None => 20,
+    };
+
+    let zero = Complex64::new(0.0, 0.0);
+    let one = Complex64::new(1.0, 0.0);
+    let plus = Complex64::new(0.5f64.sqrt(), 0.0);
+
+    // Larmor precession about z, and Rabi driving about x with and without detuning
+    let larmor = PAULI_Z * Complex64::new(OMEGA / 2.0, 0.0);
+    let rabi = PAULI_X * Complex64::new(OMEGA / 2.0, 0.0);
+    let detuned = [rabi.clone(), PAULI_Z * Complex64::new(DETUNING / 2.0, 0.0)];
+
+    println!("     t  P(+45°)  P(|1⟩)  P(|1⟩) detuned  exact");
+
+    for step in 0..=num_steps {
+        let t = 4.0 * PI * step as f64 / num_steps as f64;
+
+        let mut qb = Qubit::new(plus, plus);
+        qb.evolve(&larmor, t);
+        let precessing = qb.get_probabilities(DEG_45).as_array()[1];
+
+        let mut qb = Qubit::new(one, zero);
+        qb.evolve(&rabi, t);
+        let resonant = qb.get_probabilities(DEG_0).as_array()[1];
+
+        let mut qb = Qubit::new(one, zero);
+        qb.evolve_trotter(&detuned, t, 100).unwrap();
+        let trotter = qb.get_probabilities(DEG_0).as_array()[1];
+
+        let mut qb = Qubit::new(one, zero);
+        qb.evolve(&(detuned[0].clone() + detuned[1].clone()), t);
+        let exact = qb.get_probabilities(DEG_0).as_array()[1];
+
+        println!(
+            "{:6.3}  {:7.3}  {:6.3}  {:14.3}  {:5.3}",
+            t, precessing, resonant, trotter, exact
+        );
+    }
+
+    Ok(())
+}
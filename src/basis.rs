@@ -1,30 +1,430 @@
-use std::f64::consts::FRAC_1_SQRT_2;
+use std::{
+    error::Error,
+    f64::consts::{FRAC_1_SQRT_2, PI},
+    fmt,
+};
 
-use crate::tensor::Tensor;
+use num_complex::Complex64;
 
-pub const DEG_0: Basis = Basis(Tensor([Tensor([1.0, 0.0]), Tensor([0.0, 1.0])]));
+use crate::{
+    tensor::{Matrix, Tensor},
+    transposable::Transposable,
+};
+
+/// How far `⟨b_i|b_j⟩` may stray from `δ_ij` for the vectors to count as orthonormal.
+pub const TOLERANCE: f64 = 1e-9;
+
+pub const DEG_0: Basis = Basis(Tensor([
+    Tensor([Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)]),
+    Tensor([Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)]),
+]));
 pub const DEG_45: Basis = Basis(Tensor([
-    Tensor([FRAC_1_SQRT_2, -FRAC_1_SQRT_2]),
-    Tensor([FRAC_1_SQRT_2, FRAC_1_SQRT_2]),
+    Tensor([
+        Complex64::new(FRAC_1_SQRT_2, 0.0),
+        Complex64::new(-FRAC_1_SQRT_2, 0.0),
+    ]),
+    Tensor([
+        Complex64::new(FRAC_1_SQRT_2, 0.0),
+        Complex64::new(FRAC_1_SQRT_2, 0.0),
+    ]),
+]));
+pub const DEG_90: Basis = Basis(Tensor([
+    Tensor([Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0)]),
+    Tensor([Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)]),
 ]));
-pub const DEG_90: Basis = Basis(Tensor([Tensor([0.0, 1.0]), Tensor([1.0, 0.0])]));
 
-#[derive(Clone)]
-pub struct Basis(Tensor<Tensor<f64, 2, 1>, 2, 2>);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BasisError {
+    NotNormalized,
+    NotOrthogonal,
+    LinearlyDependent,
+}
+
+impl fmt::Display for BasisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BasisError::NotNormalized => write!(f, "basis vectors are not unit length"),
+            BasisError::NotOrthogonal => write!(f, "basis vectors are not orthogonal"),
+            BasisError::LinearlyDependent => write!(f, "vectors are linearly dependent"),
+        }
+    }
+}
+
+impl Error for BasisError {}
+
+/// Orthonormal basis of a `D`-level system, one vector per row. For qubits the
+/// first vector is the `off` outcome and the second the `on` outcome.
+#[derive(Clone, Debug)]
+pub struct Basis<const D: usize = 2>(Matrix<Complex64, D, D>);
+
+impl<const D: usize> Basis<D> {
+    /// Fails unless `vectors` are orthonormal to within `TOLERANCE`.
+    pub fn from_vectors(vectors: [Tensor<Complex64, D, 1>; D]) -> Result<Self, BasisError> {
+        let basis = Basis(Tensor(vectors));
+        let gram = basis.to_computational() * basis.to_computational().T();
+
+        for (i, row) in gram.as_array().iter().enumerate() {
+            for (j, inner) in row.as_array().iter().enumerate() {
+                if i == j && (inner - 1.0).norm() > TOLERANCE {
+                    return Err(BasisError::NotNormalized);
+                }
+
+                if i != j && inner.norm() > TOLERANCE {
+                    return Err(BasisError::NotOrthogonal);
+                }
+            }
+        }
+
+        Ok(basis)
+    }
+
+    /// Orthonormalizes `vectors` in order, keeping the direction of the first.
+    pub fn gram_schmidt(vectors: [Tensor<Complex64, D, 1>; D]) -> Result<Self, BasisError> {
+        let mut done: Vec<Tensor<Complex64, D, 1>> = Vec::with_capacity(D);
+
+        for v in vectors {
+            let scale = norm(&v);
+
+            let residual = done.iter().fold(v, |r, u| {
+                let overlap = u.clone().map(|n| n.conj()) * r.clone();
+
+                r - u.clone() * overlap
+            });
+
+            done.push(normalize(residual, scale)?);
+        }
+
+        let mut done = done.into_iter();
+
+        Ok(Basis(Tensor([(); D].map(|_| done.next().unwrap()))))
+    }
+
+    /// `|0⟩, |1⟩, ..., |D - 1⟩`
+    pub fn computational() -> Self {
+        Basis(Matrix::identity())
+    }
+
+    /// Eigenbasis of the shift operator, `|f_k⟩ = Σ_j ω^(jk) |j⟩ / √D` with
+    /// `ω = e^(2πi / D)`.
+    pub fn fourier() -> Self {
+        let norm = (D as f64).sqrt().recip();
+        let mut k = 0;
+
+        Basis(Tensor([(); D].map(|_| {
+            let mut j = 0;
+
+            let row = [(); D].map(|_| {
+                let n = Complex64::from_polar(norm, 2.0 * PI * (j * k % D) as f64 / D as f64);
+
+                j += 1;
+
+                n
+            });
+
+            k += 1;
+
+            Tensor(row)
+        })))
+    }
+
+    /// Eigenbasis of a Hermitian `observable`, sorted by ascending eigenvalue. Any
+    /// anti-Hermitian part of `observable` is ignored, as in `eigen_hermitian`.
+    pub fn from_observable(observable: &Matrix<Complex64, D, D>) -> Self {
+        let (_, vectors) = observable.eigen_hermitian();
+
+        Basis(vectors)
+    }
+
+    pub fn as_tensor(&self) -> &Matrix<Complex64, D, D> {
+        &self.0
+    }
+
+    /// Unitary mapping the `k`th basis vector onto `|k⟩`.
+    pub fn to_computational(&self) -> Matrix<Complex64, D, D> {
+        self.0.clone().map(|v| v.map(|n| n.conj()))
+    }
+}
 
 impl Basis {
-    pub const fn new(x: Tensor<f64, 2, 1>, y: Tensor<f64, 2, 1>) -> Self {
-        Basis(Tensor([x, y]))
+    /// Fails unless `x` and `y` are orthonormal to within `TOLERANCE`.
+    pub fn new(x: Tensor<Complex64, 2, 1>, y: Tensor<Complex64, 2, 1>) -> Result<Self, BasisError> {
+        Self::from_vectors([x, y])
+    }
+
+    /// Eigenbasis of Pauli Z, with `on` the spin-up state `|0⟩`, so the reverse of
+    /// `computational()` and `DEG_0`.
+    pub fn spin_z() -> Self {
+        Self::from_bloch(0.0, 0.0)
+    }
+
+    /// Eigenbasis of Pauli X, with `on` the spin-up state `|+⟩`.
+    pub fn spin_x() -> Self {
+        Self::from_bloch(PI / 2.0, 0.0)
+    }
+
+    /// Eigenbasis of Pauli Y, with `on` the spin-up state `(|0⟩ + i|1⟩) / √2`.
+    pub fn spin_y() -> Self {
+        Self::from_bloch(PI / 2.0, PI / 2.0)
+    }
+
+    /// Spin along the Bloch axis at polar angle `theta` and azimuth `phi`, with
+    /// `on` the spin-up state. Matches `from_observable` of `n·σ`.
+    pub fn from_bloch(theta: f64, phi: f64) -> Self {
+        let (sin, cos) = (theta / 2.0).sin_cos();
+
+        Self::complete(Tensor([cos.into(), Complex64::from_polar(sin, phi)]))
+    }
+
+    /// Completes `on` with its orthogonal complement as `off`. Fails unless `on`
+    /// is a unit vector to within `TOLERANCE`.
+    pub fn from_state(on: Tensor<Complex64, 2, 1>) -> Result<Self, BasisError> {
+        if (norm(&on) - 1.0).abs() > TOLERANCE {
+            return Err(BasisError::NotNormalized);
+        }
+
+        Ok(Self::complete(on))
+    }
+
+    /// The same vectors with `off` and `on` swapped, i.e. the antipodal Bloch axis.
+    pub fn complement(&self) -> Self {
+        let [off, on] = self.0.as_array().clone();
+
+        Basis(Tensor([on, off]))
     }
 
     pub fn from_radians(radians: f64) -> Self {
         Basis(Tensor([
-            Tensor([radians.cos(), -radians.sin()]),
-            Tensor([radians.sin(), radians.cos()]),
+            Tensor([radians.cos().into(), (-radians.sin()).into()]),
+            Tensor([radians.sin().into(), radians.cos().into()]),
         ]))
     }
 
-    pub fn as_tensor(&self) -> &Tensor<Tensor<f64, 2, 1>, 2, 2> {
-        &self.0
+    fn complete(on: Tensor<Complex64, 2, 1>) -> Self {
+        let [a, b] = on.0;
+
+        Basis(Tensor([Tensor([-b.conj(), a.conj()]), Tensor([a, b])]))
+    }
+}
+
+fn norm<const D: usize>(v: &Tensor<Complex64, D, 1>) -> f64 {
+    Tensor([v.clone()]).frobenius_norm()
+}
+
+/// Fails if `v` is negligible next to `scale`, the norm of the vector it was
+/// projected from.
+fn normalize<const D: usize>(
+    v: Tensor<Complex64, D, 1>,
+    scale: f64,
+) -> Result<Tensor<Complex64, D, 1>, BasisError> {
+    let norm = norm(&v);
+
+    if norm <= TOLERANCE * scale {
+        return Err(BasisError::LinearlyDependent);
+    }
+
+    Ok(v * Complex64::new(norm.recip(), 0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gate::{PAULI_X, PAULI_Y, PAULI_Z};
+
+    use super::*;
+
+    fn c(re: f64, im: f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    fn assert_close(a: &Tensor<Complex64, 2, 1>, b: &Tensor<Complex64, 2, 1>) {
+        for (x, y) in a.as_array().iter().zip(b.as_array()) {
+            assert!((x - y).norm() < 1e-12, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_new_validates() {
+        let r = FRAC_1_SQRT_2;
+
+        assert!(Basis::new(
+            Tensor([c(r, 0.0), c(0.0, r)]),
+            Tensor([c(r, 0.0), c(0.0, -r)])
+        )
+        .is_ok());
+        assert_eq!(
+            Basis::new(
+                Tensor([c(1.0, 0.0), c(1.0, 0.0)]),
+                Tensor([c(0.0, 0.0), c(1.0, 0.0)])
+            )
+            .unwrap_err(),
+            BasisError::NotNormalized
+        );
+        assert_eq!(
+            Basis::new(
+                Tensor([c(1.0, 0.0), c(0.0, 0.0)]),
+                Tensor([c(r, 0.0), c(r, 0.0)])
+            )
+            .unwrap_err(),
+            BasisError::NotOrthogonal
+        );
+    }
+
+    #[test]
+    fn test_gram_schmidt() {
+        let basis = Basis::gram_schmidt([
+            Tensor([c(2.0, 0.0), c(0.0, 2.0)]),
+            Tensor([c(1.0, 0.0), c(0.0, 0.0)]),
+        ])
+        .unwrap();
+
+        let [x, y] = basis.as_tensor().as_array().clone();
+
+        assert!(Basis::new(x.clone(), y).is_ok());
+        assert!((x.0[0] - FRAC_1_SQRT_2).norm() < 1e-12);
+        assert!((x.0[1] - c(0.0, FRAC_1_SQRT_2)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_gram_schmidt_dependent() {
+        assert_eq!(
+            Basis::gram_schmidt([
+                Tensor([c(1.0, 1.0), c(2.0, 0.0)]),
+                Tensor([c(0.0, 2.0), c(2.0, 2.0)]),
+            ])
+            .unwrap_err(),
+            BasisError::LinearlyDependent
+        );
+    }
+
+    #[test]
+    fn test_named_bases() {
+        let r = FRAC_1_SQRT_2;
+
+        for basis in [
+            Basis::spin_x(),
+            Basis::spin_y(),
+            Basis::spin_z(),
+            Basis::from_bloch(1.0, 2.0),
+        ] {
+            let [off, on] = basis.as_tensor().as_array().clone();
+
+            assert!(Basis::new(off, on).is_ok());
+        }
+
+        let on = |basis: Basis| basis.as_tensor().as_array()[1].clone();
+
+        assert_close(&on(Basis::spin_z()), &Tensor([c(1.0, 0.0), c(0.0, 0.0)]));
+        assert_eq!(
+            Basis::spin_z().complement().as_tensor(),
+            Basis::computational().as_tensor()
+        );
+        assert_close(&on(Basis::spin_x()), &Tensor([c(r, 0.0), c(r, 0.0)]));
+        assert_close(&on(Basis::spin_y()), &Tensor([c(r, 0.0), c(0.0, r)]));
+    }
+
+    #[test]
+    fn test_from_bloch_matches_observable() {
+        let (theta, phi) = (1.0f64, 2.0f64);
+        let n = [
+            theta.sin() * phi.cos(),
+            theta.sin() * phi.sin(),
+            theta.cos(),
+        ];
+        let observable = PAULI_X * c(n[0], 0.0) + PAULI_Y * c(n[1], 0.0) + PAULI_Z * c(n[2], 0.0);
+
+        let expected = Basis::from_observable(&observable);
+        let basis = Basis::from_bloch(theta, phi);
+
+        // Rows agree up to a global phase
+        for (u, v) in expected
+            .as_tensor()
+            .as_array()
+            .iter()
+            .zip(basis.as_tensor().as_array())
+        {
+            let overlap: Complex64 = u.clone().map(|n| n.conj()) * v.clone();
+
+            assert!((overlap.norm() - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_from_state() {
+        let r = FRAC_1_SQRT_2;
+        let on = Tensor([c(r, 0.0), c(0.0, r)]);
+        let [off, _] = Basis::from_state(on.clone())
+            .unwrap()
+            .as_tensor()
+            .as_array()
+            .clone();
+
+        assert_close(&off, &Tensor([c(0.0, r), c(r, 0.0)]));
+        assert!(Basis::new(off, on).is_ok());
+        assert_eq!(
+            Basis::from_state(Tensor([c(1.0, 0.0), c(1.0, 0.0)])).unwrap_err(),
+            BasisError::NotNormalized
+        );
+        assert_eq!(
+            Basis::from_state(Tensor::default()).unwrap_err(),
+            BasisError::NotNormalized
+        );
+    }
+
+    #[test]
+    fn test_complement() {
+        let basis = Basis::from_bloch(0.3, -1.2);
+        let [off, on] = basis.as_tensor().as_array().clone();
+
+        assert_eq!(basis.complement().as_tensor(), &Tensor([on, off]));
+    }
+
+    #[test]
+    fn test_gram_schmidt_scale_invariant() {
+        let basis = Basis::gram_schmidt([
+            Tensor([c(1e-10, 0.0), c(0.0, 0.0)]),
+            Tensor([c(0.0, 0.0), c(1e-10, 0.0)]),
+        ])
+        .unwrap();
+
+        assert_eq!(basis.as_tensor(), DEG_0.as_tensor());
+        assert_eq!(
+            Basis::gram_schmidt([
+                Tensor([c(1e9, 0.0), c(3e9, 1.0)]),
+                Tensor([c(2e9, 0.0), c(6e9, 2.0)]),
+            ])
+            .unwrap_err(),
+            BasisError::LinearlyDependent
+        );
+        assert_eq!(
+            Basis::gram_schmidt([
+                Tensor([c(1.0, 0.0), c(0.0, 0.0)]),
+                Tensor([c(0.0, 0.0), c(0.0, 0.0)]),
+            ])
+            .unwrap_err(),
+            BasisError::LinearlyDependent
+        );
+    }
+
+    #[test]
+    fn test_gram_schmidt_n3() {
+        let basis = Basis::<3>::gram_schmidt([
+            Tensor([c(1.0, 0.0), c(1.0, 0.0), c(0.0, 0.0)]),
+            Tensor([c(1.0, 0.0), c(0.0, 1.0), c(1.0, 0.0)]),
+            Tensor([c(0.0, 0.0), c(0.0, 0.0), c(1.0, -1.0)]),
+        ])
+        .unwrap();
+
+        assert!(Basis::from_vectors(basis.as_tensor().as_array().clone()).is_ok());
+    }
+
+    #[test]
+    fn test_fourier() {
+        let fourier = Basis::<3>::fourier();
+
+        assert!(Basis::from_vectors(fourier.as_tensor().as_array().clone()).is_ok());
+
+        // |f_0⟩ is the uniform superposition
+        for n in fourier.as_tensor().as_array()[0].as_array() {
+            assert!((n - 3f64.sqrt().recip()).norm() < 1e-12);
+        }
     }
 }
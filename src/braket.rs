@@ -1,12 +1,14 @@
 use std::{
     iter::Sum,
-    mem,
     ops::{Add, Mul},
 };
 
-use crate::{tensor_mul::TensorMul, transposable::Transposable, util::flatten_arrays};
+use crate::{
+    scalar::for_each_scalar, tensor::Tensor, tensor_mul::TensorMul, transposable::Transposable,
+    util::flatten_arrays,
+};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Bra<T, const N: usize>(pub [T; N])
 where
     T: Clone + Transposable;
@@ -75,40 +77,38 @@ where
     }
 }
 
-impl<U, T, const N: usize, const M: usize> Mul<Bra<T, M>> for Bra<T, N>
+impl<V, U, T, const N: usize, const M: usize> TensorMul<Bra<U, M>> for Bra<T, N>
 where
-    <T as Mul<T>>::Output: Clone + Transposable,
-    <T as Mul<Bra<T, M>>>::Output: Clone + Transposable,
     [(); N * M]:,
+    V: Clone + Transposable,
     U: Clone + Transposable,
-    T: Clone + Transposable + Mul<T> + Mul<Bra<T, M>, Output = Bra<U, M>>,
+    T: Clone + Transposable + TensorMul<U, Output = V>,
 {
-    type Output = Bra<U, { N * M }>;
-
-    fn mul(self, rhs: Bra<T, M>) -> Self::Output {
-        let bra_arrays = self.0.map(|it| (it * rhs.clone()).0);
-        let array = unsafe {
-            let bra_vec = bra_arrays.concat();
-            mem::transmute::<_, &[U; N * M]>(bra_vec.as_slice() as *const [U] as *const U).clone()
-        };
+    type Output = Bra<V, { N * M }>;
 
-        Bra(array)
+    fn tensor_mul(self, rhs: Bra<U, M>) -> Self::Output {
+        Bra(flatten_arrays(
+            self.0
+                .map(|a| rhs.0.clone().map(|b| a.clone().tensor_mul(b))),
+        ))
     }
 }
 
-impl<V, U, T, const N: usize, const M: usize> TensorMul<Bra<U, M>> for Bra<T, N>
+impl<T, const N: usize> From<Tensor<T, N, 1>> for Bra<T, N>
 where
-    <T as Mul<U>>::Output: Clone + Transposable,
-    <T as Mul<Bra<U, M>>>::Output: Clone + Transposable,
-    [(); N * M]:,
-    V: Clone + Transposable,
-    U: Clone + Transposable,
-    T: Clone + Transposable + Mul<U> + Mul<Bra<U, M>, Output = Bra<V, M>>,
+    T: Clone + Transposable,
 {
-    type Output = Bra<V, { N * M }>;
+    fn from(t: Tensor<T, N, 1>) -> Self {
+        Bra(t.0)
+    }
+}
 
-    fn tensor_mul(self, rhs: Bra<U, M>) -> Self::Output {
-        Bra(flatten_arrays(self.0.map(|it| (it * rhs.clone()).0)))
+impl<T, const N: usize> From<Bra<T, N>> for Tensor<T, N, 1>
+where
+    T: Clone + Transposable,
+{
+    fn from(bra: Bra<T, N>) -> Self {
+        Tensor(bra.0)
     }
 }
 
@@ -151,18 +151,50 @@ where
     }
 }
 
-impl<U: Clone + Transposable, const N: usize> Mul<Ket<U, N>> for f64
+/// Outer product `|a⟩⟨b|`, an operator stored as a ket of row bras.
+impl<U, T, const N: usize, const M: usize> Mul<Bra<U, M>> for Ket<T, N>
 where
-    <f64 as Mul<U>>::Output: Clone + Transposable,
-    f64: Mul<U>,
+    <T as Mul<U>>::Output: Clone + Transposable,
+    U: Clone + Transposable,
+    T: Clone + Transposable + Mul<U>,
 {
-    type Output = Ket<<f64 as Mul<U>>::Output, N>;
+    type Output = Ket<Bra<<T as Mul<U>>::Output, M>, N>;
 
-    fn mul(self, rhs: Ket<U, N>) -> Self::Output {
-        Ket(rhs.0.map(|it| self * it))
+    fn mul(self, rhs: Bra<U, M>) -> Self::Output {
+        Ket(self.0.map(|a| rhs.clone().map(|b| a.clone() * b)))
     }
 }
 
+macro_rules! impl_scalar_mul_braket {
+    ($($s:ty),*) => {$(
+        impl<U: Clone + Transposable, const N: usize> Mul<Ket<U, N>> for $s
+        where
+            <$s as Mul<U>>::Output: Clone + Transposable,
+            $s: Mul<U>,
+        {
+            type Output = Ket<<$s as Mul<U>>::Output, N>;
+
+            fn mul(self, rhs: Ket<U, N>) -> Self::Output {
+                Ket(rhs.0.map(|it| self * it))
+            }
+        }
+
+        impl<U: Clone + Transposable, const N: usize> Mul<Bra<U, N>> for $s
+        where
+            <$s as Mul<U>>::Output: Clone + Transposable,
+            $s: Mul<U>,
+        {
+            type Output = Bra<<$s as Mul<U>>::Output, N>;
+
+            fn mul(self, rhs: Bra<U, N>) -> Self::Output {
+                Bra(rhs.0.map(|it| self * it))
+            }
+        }
+    )*};
+}
+
+for_each_scalar!(impl_scalar_mul_braket);
+
 impl<U: Clone + Transposable, T: Clone + Transposable + Add<U>, const N: usize> Add<Ket<U, N>>
     for Ket<T, N>
 where
@@ -179,22 +211,61 @@ where
 
 impl<V, U, T, const N: usize, const M: usize> TensorMul<Ket<U, M>> for Ket<T, N>
 where
-    <T as Mul<U>>::Output: Clone + Transposable,
-    <T as Mul<Ket<U, M>>>::Output: Clone + Transposable,
     [(); N * M]:,
     V: Clone + Transposable,
     U: Clone + Transposable,
-    T: Clone + Transposable + Mul<U> + Mul<Ket<U, M>, Output = Ket<V, M>>,
+    T: Clone + Transposable + TensorMul<U, Output = V>,
 {
     type Output = Ket<V, { N * M }>;
 
     fn tensor_mul(self, rhs: Ket<U, M>) -> Self::Output {
-        Ket(flatten_arrays(self.0.map(|it| (it * rhs.clone()).0)))
+        Ket(flatten_arrays(
+            self.0
+                .map(|a| rhs.0.clone().map(|b| a.clone().tensor_mul(b))),
+        ))
+    }
+}
+
+impl<T, const N: usize> From<Tensor<T, N, 1>> for Ket<T, N>
+where
+    T: Clone + Transposable,
+{
+    fn from(t: Tensor<T, N, 1>) -> Self {
+        Ket(t.0)
+    }
+}
+
+impl<T, const N: usize> From<Ket<T, N>> for Tensor<T, N, 1>
+where
+    T: Clone + Transposable,
+{
+    fn from(ket: Ket<T, N>) -> Self {
+        Tensor(ket.0)
+    }
+}
+
+impl<T, const N: usize, const M: usize> From<Tensor<Tensor<T, M, 1>, N, 2>> for Ket<Bra<T, M>, N>
+where
+    T: Clone + Transposable,
+{
+    fn from(t: Tensor<Tensor<T, M, 1>, N, 2>) -> Self {
+        Ket(t.0.map(Bra::from))
+    }
+}
+
+impl<T, const N: usize, const M: usize> From<Ket<Bra<T, M>, N>> for Tensor<Tensor<T, M, 1>, N, 2>
+where
+    T: Clone + Transposable,
+{
+    fn from(op: Ket<Bra<T, M>, N>) -> Self {
+        Tensor(op.0.map(Tensor::from))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use num_complex::Complex64;
+
     use super::*;
 
     #[test]
@@ -206,9 +277,50 @@ mod tests {
     }
 
     #[test]
-    fn test_tensors_mul() {
-        let t = Bra([Ket([1.0, 0.0]), Ket([0.0, 1.0])]);
+    fn test_operators_tensor_mul() {
+        let x = Ket([Bra([0.0, 1.0]), Bra([1.0, 0.0])]);
+        let i = Ket([Bra([1.0, 0.0]), Bra([0.0, 1.0])]);
+
+        assert_eq!(
+            x.tensor_mul(i),
+            Ket([
+                Bra([0.0, 0.0, 1.0, 0.0]),
+                Bra([0.0, 0.0, 0.0, 1.0]),
+                Bra([1.0, 0.0, 0.0, 0.0]),
+                Bra([0.0, 1.0, 0.0, 0.0]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_inner_product() {
+        let a = Ket([Complex64::new(1.0, 1.0), Complex64::new(0.0, 2.0)]);
+        let b = Ket([Complex64::new(3.0, 0.0), Complex64::new(1.0, 0.0)]);
+
+        assert_eq!(a.T() * b, Complex64::new(3.0, -5.0));
+    }
+
+    #[test]
+    fn test_outer_product() {
+        let a = Ket([1.0, 2.0]);
+        let b = Ket([3.0, 4.0]);
+
+        let op = a * b.T();
+
+        assert_eq!(op, Ket([Bra([3.0, 4.0]), Bra([6.0, 8.0])]));
+        assert_eq!(op * Ket([1.0, 0.0]), Ket([3.0, 6.0]));
+    }
+
+    #[test]
+    fn test_tensor_conversions() {
+        let t: Tensor<f64, 2, 1> = Tensor([1.0, 2.0]);
+        let ket = Ket::from(t.clone());
+
+        assert_eq!(ket, Ket([1.0, 2.0]));
+        assert_eq!(Tensor::from(ket), t);
+
+        let op: Tensor<Tensor<f64, 2, 1>, 2, 2> = Tensor([Tensor([1.0, 2.0]), Tensor([3.0, 4.0])]);
 
-        assert_eq!(t.tensor_mul(t), Ket([3.0, 4.0, 6.0, 8.0]));
+        assert_eq!(Tensor::from(Ket::from(op.clone())), op);
     }
 }
@@ -0,0 +1,273 @@
+use std::{error::Error, fmt};
+
+use num_complex::Complex64;
+
+use crate::{
+    density_matrix::DensityMatrix,
+    gate::{Gate, IDENTITY, PAULI_X, PAULI_Y, PAULI_Z},
+    tensor::{Matrix, Tensor},
+    transposable::Transposable,
+};
+
+/// Returned by the noise constructors of `Channel` for a probability outside `[0, 1]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProbabilityError;
+
+impl fmt::Display for ProbabilityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "probability is not in [0, 1]")
+    }
+}
+
+impl Error for ProbabilityError {}
+
+/// Noisy evolution `ρ → Σ_k K_k ρ K_k†` of an `N`-level system, given by its
+/// Kraus operators `K_k`.
+#[derive(Clone, Debug)]
+pub struct Channel<const N: usize = 2> {
+    kraus: Vec<Gate<N>>,
+}
+
+impl<const N: usize> Channel<N> {
+    /// Trace preserving only if `Σ_k K_k† K_k = I`, see `is_trace_preserving`.
+    pub fn new(kraus: Vec<Gate<N>>) -> Self {
+        Channel { kraus }
+    }
+
+    /// Noiseless evolution under `u`.
+    pub fn unitary(u: Gate<N>) -> Self {
+        Channel { kraus: vec![u] }
+    }
+
+    pub fn identity() -> Self {
+        Self::unitary(Matrix::identity())
+    }
+
+    pub fn get_kraus_operators(&self) -> &[Gate<N>] {
+        &self.kraus
+    }
+
+    pub fn is_trace_preserving(&self) -> bool {
+        let completeness: Gate<N> = self.kraus.iter().map(|k| k.T() * k.clone()).sum();
+
+        (completeness - Gate::<N>::identity()).frobenius_norm() < 1e-9
+    }
+
+    pub fn apply(&self, rho: &DensityMatrix<N>) -> DensityMatrix<N> {
+        DensityMatrix::new(
+            self.kraus
+                .iter()
+                .map(|k| k.clone() * rho.as_tensor().clone() * k.T())
+                .sum(),
+        )
+    }
+
+    /// `self` followed by `next`, with Kraus operators `B_j A_i`.
+    pub fn then(&self, next: &Channel<N>) -> Self {
+        Channel {
+            kraus: next
+                .kraus
+                .iter()
+                .flat_map(|b| self.kraus.iter().map(move |a| b.clone() * a.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl Channel {
+    /// Applies Pauli X with probability `p`.
+    pub fn bit_flip(p: f64) -> Result<Self, ProbabilityError> {
+        check_probability(p)?;
+
+        Ok(Self::pauli(1.0 - p, [p, 0.0, 0.0]))
+    }
+
+    /// Applies Pauli Z with probability `p`.
+    pub fn phase_flip(p: f64) -> Result<Self, ProbabilityError> {
+        check_probability(p)?;
+
+        Ok(Self::pauli(1.0 - p, [0.0, 0.0, p]))
+    }
+
+    /// Replaces the state with `I / 2` with probability `p`, shrinking the Bloch
+    /// vector by `1 - p`.
+    pub fn depolarizing(p: f64) -> Result<Self, ProbabilityError> {
+        check_probability(p)?;
+
+        Ok(Self::pauli(1.0 - 0.75 * p, [0.25 * p; 3]))
+    }
+
+    /// Decay of `|1⟩` to `|0⟩` with probability `gamma`, e.g. energy loss.
+    pub fn amplitude_damping(gamma: f64) -> Result<Self, ProbabilityError> {
+        check_probability(gamma)?;
+
+        Ok(Self::damping(gamma, false))
+    }
+
+    /// Loss of coherence without energy loss, shrinking the off-diagonal terms
+    /// by `√(1 - lambda)`.
+    pub fn phase_damping(lambda: f64) -> Result<Self, ProbabilityError> {
+        check_probability(lambda)?;
+
+        Ok(Self::damping(lambda, true))
+    }
+
+    /// `p_i` with Pauli X, Y and Z, and `p_identity` with no change.
+    fn pauli(p_identity: f64, p: [f64; 3]) -> Self {
+        Channel {
+            kraus: [p_identity, p[0], p[1], p[2]]
+                .into_iter()
+                .zip([IDENTITY, PAULI_X, PAULI_Y, PAULI_Z])
+                .filter(|(p, _)| *p > 0.0)
+                .map(|(p, k)| k * Complex64::new(p.sqrt(), 0.0))
+                .collect(),
+        }
+    }
+
+    fn damping(gamma: f64, keep_excitation: bool) -> Self {
+        let zero = Complex64::default();
+        let survive = Complex64::new((1.0 - gamma).sqrt(), 0.0);
+        let decay = Complex64::new(gamma.sqrt(), 0.0);
+
+        let jump = if keep_excitation {
+            Tensor([Tensor([zero, zero]), Tensor([zero, decay])])
+        } else {
+            Tensor([Tensor([zero, decay]), Tensor([zero, zero])])
+        };
+
+        Channel {
+            kraus: vec![
+                Tensor([
+                    Tensor([Complex64::new(1.0, 0.0), zero]),
+                    Tensor([zero, survive]),
+                ]),
+                jump,
+            ],
+        }
+    }
+}
+
+fn check_probability(p: f64) -> Result<(), ProbabilityError> {
+    if !(0.0..=1.0).contains(&p) {
+        return Err(ProbabilityError);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{basis::DEG_45, filter::Filter};
+
+    use super::*;
+
+    fn assert_bloch_close(rho: &DensityMatrix<2>, expected: [f64; 3]) {
+        for (x, y) in rho.get_bloch_vector().0.iter().zip(expected) {
+            assert!((x - y).abs() < 1e-12, "{:?} != {:?}", rho, expected);
+        }
+    }
+
+    #[test]
+    fn test_trace_preserving() {
+        for channel in [
+            Channel::bit_flip(0.1).unwrap(),
+            Channel::phase_flip(0.2).unwrap(),
+            Channel::depolarizing(0.3).unwrap(),
+            Channel::amplitude_damping(0.4).unwrap(),
+            Channel::phase_damping(0.5).unwrap(),
+            Channel::depolarizing(0.3)
+                .unwrap()
+                .then(&Channel::amplitude_damping(0.4).unwrap()),
+        ] {
+            assert!(channel.is_trace_preserving());
+        }
+
+        assert!(!Channel::new(vec![PAULI_X * Complex64::new(0.5, 0.0)]).is_trace_preserving());
+    }
+
+    #[test]
+    fn test_invalid_probability() {
+        assert_eq!(Channel::bit_flip(-0.1).unwrap_err(), ProbabilityError);
+        assert_eq!(
+            Channel::amplitude_damping(1.5).unwrap_err(),
+            ProbabilityError
+        );
+        assert_eq!(
+            Channel::depolarizing(f64::NAN).unwrap_err(),
+            ProbabilityError
+        );
+    }
+
+    #[test]
+    fn test_bloch_contraction() {
+        let rho = DensityMatrix::from_bloch_vector(Tensor([0.6, 0.0, 0.8]));
+
+        assert_bloch_close(
+            &Channel::bit_flip(0.25).unwrap().apply(&rho),
+            [0.6, 0.0, 0.4],
+        );
+        assert_bloch_close(
+            &Channel::phase_flip(0.25).unwrap().apply(&rho),
+            [0.3, 0.0, 0.8],
+        );
+        assert_bloch_close(
+            &Channel::depolarizing(0.5).unwrap().apply(&rho),
+            [0.3, 0.0, 0.4],
+        );
+        assert_bloch_close(
+            &Channel::phase_damping(0.75).unwrap().apply(&rho),
+            [0.3, 0.0, 0.8],
+        );
+
+        // Full damping leaves |0⟩ whatever the input
+        assert_bloch_close(
+            &Channel::amplitude_damping(1.0).unwrap().apply(&rho),
+            [0.0, 0.0, 1.0],
+        );
+    }
+
+    #[test]
+    fn test_composition() {
+        let rho = DensityMatrix::from_bloch_vector(Tensor([0.6, 0.0, 0.8]));
+        let channel = Channel::depolarizing(0.5)
+            .unwrap()
+            .then(&Channel::depolarizing(0.5).unwrap());
+
+        assert_eq!(channel.get_kraus_operators().len(), 16);
+        assert_bloch_close(&channel.apply(&rho), [0.15, 0.0, 0.2]);
+        assert_bloch_close(
+            &Channel::unitary(PAULI_X)
+                .then(&Channel::identity())
+                .apply(&rho),
+            [0.6, 0.0, -0.8],
+        );
+    }
+
+    #[test]
+    fn test_noise_between_filters() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let noise = Channel::depolarizing(0.4).unwrap();
+
+        let mut first = Filter::new(DEG_45, true);
+        let mut second = Filter::new(DEG_45, true);
+
+        // Fidelity with the polarization both filters let through drops to 1 - p / 2
+        let degraded = noise.apply(&DensityMatrix::from_pure(first.get_pass_state()));
+        let predicted = second.predicted_pass_probability(degraded.clone());
+
+        assert!((degraded.fidelity(&first.get_pass_state()) - 0.8).abs() < 1e-12);
+        assert!((predicted - 0.8).abs() < 1e-12);
+
+        for _ in 0..10_000 {
+            if let Some(rho) = first.filter_mixed(DensityMatrix::maximally_mixed(), &mut rng) {
+                second.filter_mixed(noise.apply(&rho), &mut rng);
+            }
+        }
+
+        assert!(first.get_deviation(0.5).abs() < 3.0);
+        assert!(second.get_deviation(predicted).abs() < 3.0);
+        assert!(second.get_deviation(1.0).abs() > 3.0);
+    }
+}
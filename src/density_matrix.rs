@@ -0,0 +1,463 @@
+use num_complex::Complex64;
+use rand::Rng;
+
+use crate::{
+    basis::Basis,
+    gate::PAULI_Y,
+    outer_mul::OuterMul,
+    qubit::Qubit,
+    tensor::{Matrix, Tensor},
+    tensor_mul::TensorMul,
+    util::subsystem_offsets,
+};
+
+/// Mixed state of an `N`-level system, `ρ = Σ p_i |ψ_i⟩⟨ψ_i|`, stored row-major.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DensityMatrix<const N: usize>(Matrix<Complex64, N, N>);
+
+impl<const N: usize> DensityMatrix<N> {
+    /// Wraps `rho` without checking that it is Hermitian, positive and unit trace.
+    pub fn new(rho: Matrix<Complex64, N, N>) -> Self {
+        DensityMatrix(rho)
+    }
+
+    /// `|ψ⟩⟨ψ|`
+    pub fn from_pure(state: Tensor<Complex64, N, 1>) -> Self {
+        let bra = state.clone().map(|n| n.conj());
+
+        DensityMatrix(state.outer_mul(bra))
+    }
+
+    /// Weighted mixture of pure states. Weights are expected to sum to 1.
+    pub fn from_ensemble(ensemble: &[(f64, Tensor<Complex64, N, 1>)]) -> Self {
+        DensityMatrix(
+            ensemble
+                .iter()
+                .map(|(p, state)| Self::from_pure(state.clone()).0 * Complex64::new(*p, 0.0))
+                .sum(),
+        )
+    }
+
+    /// `I / N`, e.g. unpolarized light for `N = 2`.
+    pub fn maximally_mixed() -> Self {
+        let mut rho = Tensor::<Tensor<Complex64, N, 1>, N, 2>::default();
+
+        for (i, row) in rho.0.iter_mut().enumerate() {
+            row.0[i] = Complex64::new(1.0 / N as f64, 0.0);
+        }
+
+        DensityMatrix(rho)
+    }
+
+    pub fn as_tensor(&self) -> &Matrix<Complex64, N, N> {
+        &self.0
+    }
+
+    /// `Tr(ρ²)`: 1 for pure states, down to `1 / N` for the maximally mixed state.
+    pub fn purity(&self) -> f64 {
+        // For Hermitian ρ, Tr(ρ²) = Σ |ρ_ij|²
+        self.0
+            .as_array()
+            .iter()
+            .flat_map(|row| row.as_array())
+            .map(|n| n.norm_sqr())
+            .sum()
+    }
+
+    /// `Tr(ρ O)`, real for Hermitian `observable`.
+    pub fn expectation(&self, observable: &Matrix<Complex64, N, N>) -> f64 {
+        (self.0.clone() * observable.clone()).trace().re
+    }
+
+    /// Reduced state of the qubits in `keep`, tracing out every other qubit, with
+    /// qubit 0 the most significant bit as in `Register`. Panics unless `N` is a
+    /// power of two.
+    pub fn partial_trace<const K: usize>(&self, keep: [usize; K]) -> DensityMatrix<{ 1 << K }>
+    where
+        [(); 1 << K]:,
+    {
+        assert!(N.is_power_of_two(), "dimension {} is not a power of two", N);
+
+        let num_qubits = N.trailing_zeros() as usize;
+        let rest: Vec<usize> = (0..num_qubits).filter(|q| !keep.contains(q)).collect();
+
+        let kept = subsystem_offsets(&keep, num_qubits);
+        let traced = subsystem_offsets(&rest, num_qubits);
+
+        let mut rho = Tensor::<Tensor<Complex64, { 1 << K }, 1>, { 1 << K }, 2>::default();
+
+        for (row, i) in rho.0.iter_mut().zip(&kept) {
+            for (n, j) in row.0.iter_mut().zip(&kept) {
+                *n = traced.iter().map(|r| self.0 .0[i | r].0[j | r]).sum();
+            }
+        }
+
+        DensityMatrix(rho)
+    }
+
+    /// `ρ^(T_A)`, transposing the indices of the qubits in `qubits` only. A negative
+    /// eigenvalue witnesses entanglement between those qubits and the rest. Panics
+    /// unless `N` is a power of two.
+    pub fn partial_transpose(&self, qubits: &[usize]) -> Self {
+        assert!(N.is_power_of_two(), "dimension {} is not a power of two", N);
+
+        // The joint state with every qubit set
+        let mask = *subsystem_offsets(qubits, N.trailing_zeros() as usize)
+            .last()
+            .unwrap();
+
+        let mut rho = self.0.clone();
+
+        for (r, row) in rho.0.iter_mut().enumerate() {
+            for (c, n) in row.0.iter_mut().enumerate() {
+                *n = self.0 .0[(r & !mask) | (c & mask)].0[(c & !mask) | (r & mask)];
+            }
+        }
+
+        DensityMatrix(rho)
+    }
+
+    /// `log₂ ‖ρ^(T_A)‖₁` between the qubits in `qubits` and the rest: 0 for
+    /// separable states, 1 for a Bell pair.
+    pub fn log_negativity(&self, qubits: &[usize]) -> f64 {
+        let (values, _) = self.partial_transpose(qubits).0.eigen_hermitian();
+
+        values
+            .0
+            .iter()
+            .map(|v| v.abs())
+            .sum::<f64>()
+            .log2()
+            .max(0.0)
+    }
+
+    /// `⟨ψ|ρ|ψ⟩`, the chance of finding `ρ` in the pure `state`.
+    pub fn fidelity(&self, state: &Tensor<Complex64, N, 1>) -> f64 {
+        let bra = state.clone().map(|n| n.conj());
+
+        (bra * (self.0.clone() * state.clone())).re
+    }
+
+    /// Von Neumann entropy `-Tr(ρ log₂ ρ)` in bits.
+    pub fn entropy(&self) -> f64 {
+        let (values, _) = self.0.eigen_hermitian();
+
+        values
+            .0
+            .into_iter()
+            .filter(|&p| p > 0.0)
+            .map(|p| -p * p.log2())
+            .sum()
+    }
+}
+
+impl DensityMatrix<4> {
+    /// Wootters concurrence of a two-qubit state, 0 for separable states up to 1
+    /// for Bell states.
+    pub fn concurrence(&self) -> f64 {
+        let flip = PAULI_Y.tensor_mul(PAULI_Y);
+        let rho_tilde = flip.clone() * self.0.clone().map(|row| row.map(|n| n.conj())) * flip;
+
+        let sqrt_rho = self.sqrt();
+        let (values, _) = (sqrt_rho.clone() * rho_tilde * sqrt_rho).eigen_hermitian();
+
+        // Ascending, so the largest is last
+        let [l4, l3, l2, l1] = values.0.map(|v| v.max(0.0).sqrt());
+
+        (l1 - l2 - l3 - l4).max(0.0)
+    }
+
+    /// Entanglement of formation in bits, from the concurrence.
+    pub fn entanglement_of_formation(&self) -> f64 {
+        let c = self.concurrence();
+        let p = (1.0 + (1.0 - c * c).max(0.0).sqrt()) / 2.0;
+
+        [p, 1.0 - p]
+            .into_iter()
+            .filter(|&p| p > 0.0)
+            .map(|p| -p * p.log2())
+            .sum()
+    }
+
+    /// `√ρ`, through the eigendecomposition.
+    fn sqrt(&self) -> Matrix<Complex64, 4, 4> {
+        let (values, vectors) = self.0.eigen_hermitian();
+
+        values
+            .0
+            .into_iter()
+            .zip(vectors.0)
+            .map(|(p, v)| {
+                let bra = v.clone().map(|n| n.conj());
+
+                v.outer_mul(bra) * Complex64::new(p.max(0.0).sqrt(), 0.0)
+            })
+            .sum()
+    }
+}
+
+impl DensityMatrix<2> {
+    /// `(I + r·σ) / 2`. `vector` must have length at most 1, with 1 a pure state.
+    pub fn from_bloch_vector(vector: Tensor<f64, 3, 1>) -> Self {
+        let [x, y, z] = vector.0;
+
+        DensityMatrix(Tensor([
+            Tensor([
+                Complex64::new((1.0 + z) / 2.0, 0.0),
+                Complex64::new(x, -y) / 2.0,
+            ]),
+            Tensor([
+                Complex64::new(x, y) / 2.0,
+                Complex64::new((1.0 - z) / 2.0, 0.0),
+            ]),
+        ]))
+    }
+
+    /// `(x, y, z)` expectations of the Pauli operators. Shorter than 1 for mixed
+    /// states, zero for the maximally mixed state.
+    pub fn get_bloch_vector(&self) -> Tensor<f64, 3, 1> {
+        let [[rho_00, _], [rho_10, rho_11]] = self.0.as_array().clone().map(|row| row.0);
+
+        Tensor([2.0 * rho_10.re, 2.0 * rho_10.im, (rho_00 - rho_11).re])
+    }
+
+    /// Probabilities of the `off` and `on` outcomes, `⟨b|ρ|b⟩`.
+    pub fn get_probabilities(&self, basis: Basis) -> Tensor<f64, 2, 1> {
+        Tensor(basis.as_tensor().as_array().clone().map(|b| {
+            let mut sum = Complex64::default();
+
+            for (j, row) in self.0.as_array().iter().enumerate() {
+                for (k, rho_jk) in row.as_array().iter().enumerate() {
+                    sum += b.0[j].conj() * rho_jk * b.0[k];
+                }
+            }
+
+            sum.re
+        }))
+    }
+
+    /// Projective measurement. The state collapses onto the observed basis vector.
+    pub fn measure<R: Rng + ?Sized>(&mut self, basis: Basis, rng: &mut R) -> bool {
+        let [off_state, on_state] = basis.as_tensor().as_array().clone();
+
+        let &[off_p, on_p] = self.get_probabilities(basis).as_array();
+
+        debug_assert!((1.0 - (off_p + on_p)).abs() < 0.0001);
+
+        let is_on = rng.gen_bool(on_p.clamp(0.0, 1.0));
+
+        *self = Self::from_pure(if is_on { on_state } else { off_state });
+
+        is_on
+    }
+}
+
+impl From<&Qubit> for DensityMatrix<2> {
+    fn from(qb: &Qubit) -> Self {
+        Self::from_pure(qb.get_state().clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        basis::{DEG_0, DEG_45},
+        entangled_pair::EntangledPair,
+        gate::{CNOT, HADAMARD, PAULI_X, PAULI_Z},
+        register::Register,
+    };
+
+    use super::*;
+
+    fn c(re: f64) -> Complex64 {
+        Complex64::new(re, 0.0)
+    }
+
+    fn assert_close<const N: usize>(a: &DensityMatrix<N>, b: &DensityMatrix<N>) {
+        for (row_a, row_b) in a.0.as_array().iter().zip(b.0.as_array()) {
+            for (x, y) in row_a.as_array().iter().zip(row_b.as_array()) {
+                assert!((x - y).norm() < 1e-12, "{:?} != {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pure_qubit() {
+        let rho = DensityMatrix::from(&Qubit::new(c(FRAC_1_SQRT_2), c(FRAC_1_SQRT_2)));
+
+        assert!((rho.purity() - 1.0).abs() < 1e-12);
+        assert!(rho.entropy().abs() < 1e-6);
+        assert!(rho.expectation(&PAULI_Z).abs() < 1e-12);
+
+        let &[off_p, on_p] = rho.get_probabilities(DEG_45).as_array();
+
+        assert!(off_p.abs() < 1e-12 && (on_p - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_maximally_mixed() {
+        let rho = DensityMatrix::<2>::maximally_mixed();
+
+        assert!((rho.purity() - 0.5).abs() < 1e-12);
+        assert!((rho.entropy() - 1.0).abs() < 1e-12);
+        assert_eq!(rho.get_probabilities(DEG_0), Tensor([0.5, 0.5]));
+    }
+
+    #[test]
+    fn test_ensemble() {
+        let rho = DensityMatrix::from_ensemble(&[
+            (0.75, Tensor([c(1.0), c(0.0)])),
+            (0.25, Tensor([c(0.0), c(1.0)])),
+        ]);
+
+        assert!((rho.expectation(&PAULI_Z) - 0.5).abs() < 1e-12);
+        assert!((rho.purity() - 0.625).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_bloch_vector() {
+        let qb = Qubit::from_bloch(0.7, 2.5);
+        let pure = DensityMatrix::from(&qb).get_bloch_vector();
+
+        for (a, b) in pure.as_array().iter().zip(qb.get_bloch_vector().as_array()) {
+            assert!((a - b).abs() < 1e-12);
+        }
+
+        let rho = DensityMatrix::from_bloch_vector(Tensor([0.3, -0.2, 0.4]));
+        let [x, y, z] = rho.get_bloch_vector().0;
+
+        assert!((x - 0.3).abs() < 1e-12 && (y + 0.2).abs() < 1e-12 && (z - 0.4).abs() < 1e-12);
+        // Tr(ρ²) = (1 + |r|²) / 2
+        assert!((rho.purity() - (1.0 + 0.29) / 2.0).abs() < 1e-12);
+        assert_eq!(
+            DensityMatrix::<2>::maximally_mixed().get_bloch_vector(),
+            Tensor([0.0, 0.0, 0.0])
+        );
+    }
+
+    #[test]
+    fn test_measure_collapses() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut rho = DensityMatrix::<2>::maximally_mixed();
+
+        let is_on = rho.measure(DEG_0, &mut rng);
+
+        assert!((rho.purity() - 1.0).abs() < 1e-12);
+        assert_eq!(rho.measure(DEG_0, &mut rng), is_on);
+    }
+
+    #[test]
+    fn test_pair_state() {
+        let rho = DensityMatrix::from_pure(EntangledPair::phi_plus().get_state().clone());
+
+        assert!((rho.purity() - 1.0).abs() < 1e-12);
+        assert!(rho.entropy().abs() < 1e-12);
+        assert!((DensityMatrix::<4>::maximally_mixed().entropy() - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_bell_half_is_maximally_mixed() {
+        let rho = DensityMatrix::from_pure(EntangledPair::phi_plus().get_state().clone());
+
+        for half in [0, 1] {
+            let reduced = rho.partial_trace([half]);
+
+            assert_close(&reduced, &DensityMatrix::maximally_mixed());
+            assert!((reduced.entropy() - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate qubit index")]
+    fn test_partial_trace_duplicate_qubit() {
+        DensityMatrix::<4>::maximally_mixed().partial_trace([0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension 6 is not a power of two")]
+    fn test_partial_trace_not_qubits() {
+        DensityMatrix::<6>::maximally_mixed().partial_trace([0]);
+    }
+
+    #[test]
+    fn test_partial_trace_of_product() {
+        let mut reg = Register::<3>::new();
+
+        // |ψ⟩ = |1⟩ ⊗ |Φ+⟩ on qubits (1, 0, 2)
+        reg.apply(&PAULI_X, [1]);
+        reg.apply(&HADAMARD, [2]);
+        reg.apply(&CNOT, [2, 0]);
+
+        let rho = DensityMatrix::from_pure(reg.get_state().clone());
+
+        assert_close(
+            &rho.partial_trace([1]),
+            &DensityMatrix::from_pure(Tensor([c(0.0), c(1.0)])),
+        );
+        assert_close(
+            &rho.partial_trace([2, 0]),
+            &DensityMatrix::from_pure(EntangledPair::phi_plus().get_state().clone()),
+        );
+        assert!((rho.partial_trace([0, 1]).purity() - 0.5).abs() < 1e-12);
+        assert!((rho.partial_trace::<0>([]).0 .0[0].0[0] - 1.0).norm() < 1e-12);
+    }
+
+    /// `p |Ψ-⟩⟨Ψ-| + (1 - p) I / 4`
+    fn werner(p: f64) -> DensityMatrix<4> {
+        DensityMatrix::new(
+            DensityMatrix::from_pure(EntangledPair::psi_minus().get_state().clone()).0 * c(p)
+                + DensityMatrix::<4>::maximally_mixed().0 * c(1.0 - p),
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_partial_transpose_out_of_range() {
+        DensityMatrix::<4>::maximally_mixed().partial_transpose(&[2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension 6 is not a power of two")]
+    fn test_partial_transpose_not_qubits() {
+        DensityMatrix::<6>::maximally_mixed().partial_transpose(&[0]);
+    }
+
+    #[test]
+    fn test_bell_entanglement() {
+        for pair in [EntangledPair::phi_plus(), EntangledPair::psi_minus()] {
+            let rho = DensityMatrix::from_pure(pair.get_state().clone());
+
+            assert!((rho.concurrence() - 1.0).abs() < 1e-9);
+            assert!((rho.entanglement_of_formation() - 1.0).abs() < 1e-9);
+            assert!((rho.log_negativity(&[0]) - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_product_entanglement() {
+        let state =
+            Tensor([c(0.6), c(0.8)]).tensor_mul(Tensor([c(FRAC_1_SQRT_2), c(-FRAC_1_SQRT_2)]));
+        let rho = DensityMatrix::from_pure(state);
+
+        assert!(rho.concurrence() < 1e-6);
+        assert!(rho.entanglement_of_formation() < 1e-6);
+        assert!(rho.log_negativity(&[1]) < 1e-9);
+        assert!(DensityMatrix::<4>::maximally_mixed().concurrence() < 1e-9);
+    }
+
+    #[test]
+    fn test_werner_entanglement() {
+        for p in [0.2, 0.5, 0.9] {
+            let rho = werner(p);
+
+            assert!((rho.concurrence() - ((3.0 * p - 1.0) / 2.0).max(0.0)).abs() < 1e-9);
+            assert!(
+                (rho.log_negativity(&[0]) - ((1.0 + 3.0 * p) / 2.0).log2().max(0.0)).abs() < 1e-9
+            );
+        }
+    }
+}
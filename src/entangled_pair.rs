@@ -1,7 +1,195 @@
-use crate::qubit::Qubit;
+use std::f64::consts::FRAC_1_SQRT_2;
 
-pub struct EntangledPair(Qubit, Qubit);
+use num_complex::Complex64;
+use rand::Rng;
+
+use crate::{
+    basis::Basis, density_matrix::DensityMatrix, register::Register, schmidt, tensor::Tensor,
+};
+
+/// Two qubits sharing a joint 4-amplitude state, ordered `|00⟩, |01⟩, |10⟩, |11⟩`.
+#[derive(Clone, Debug)]
+pub struct EntangledPair(Register<2>);
 
 impl EntangledPair {
-    
-}
\ No newline at end of file
+    pub fn new(state: Tensor<Complex64, 4, 1>) -> Self {
+        EntangledPair(Register::from_state(state))
+    }
+
+    /// `(|00⟩ + |11⟩) / √2`
+    pub fn phi_plus() -> Self {
+        Self::bell(0, 3, 1.0)
+    }
+
+    /// `(|00⟩ - |11⟩) / √2`
+    pub fn phi_minus() -> Self {
+        Self::bell(0, 3, -1.0)
+    }
+
+    /// `(|01⟩ + |10⟩) / √2`
+    pub fn psi_plus() -> Self {
+        Self::bell(1, 2, 1.0)
+    }
+
+    /// `(|01⟩ - |10⟩) / √2`
+    pub fn psi_minus() -> Self {
+        Self::bell(1, 2, -1.0)
+    }
+
+    fn bell(a: usize, b: usize, sign: f64) -> Self {
+        let mut state = Tensor::<Complex64, 4, 1>::default();
+        state.0[a] = Complex64::new(FRAC_1_SQRT_2, 0.0);
+        state.0[b] = Complex64::new(sign * FRAC_1_SQRT_2, 0.0);
+
+        Self::new(state)
+    }
+
+    pub fn get_state(&self) -> &Tensor<Complex64, 4, 1> {
+        self.0.get_state()
+    }
+
+    pub fn as_register(&self) -> &Register<2> {
+        &self.0
+    }
+
+    /// State of one half (`0` or `1`) on its own, with the other half traced out.
+    pub fn get_reduced_state(&self, half: usize) -> DensityMatrix<2> {
+        DensityMatrix::from_pure(self.get_state().clone()).partial_trace([half])
+    }
+
+    /// Wootters concurrence, 1 for every Bell state.
+    pub fn get_concurrence(&self) -> f64 {
+        DensityMatrix::from_pure(self.get_state().clone()).concurrence()
+    }
+
+    /// Whether the two halves are independent, `|a⟩ ⊗ |b⟩`.
+    pub fn is_product_state(&self) -> bool {
+        schmidt::is_product_state::<2, 2>(self.get_state())
+    }
+
+    /// Entanglement entropy in bits between the two halves, from the Schmidt
+    /// coefficients.
+    pub fn get_entanglement_entropy(&self) -> f64 {
+        schmidt::entanglement_entropy::<2, 2>(self.get_state())
+    }
+
+    /// Joint probabilities of `(off, off), (off, on), (on, off), (on, on)`.
+    pub fn get_probabilities(&self, basis_a: Basis, basis_b: Basis) -> Tensor<f64, 4, 1> {
+        let mut reg = self.0.clone();
+
+        reg.apply(&basis_a.to_computational(), [0]);
+        reg.apply(&basis_b.to_computational(), [1]);
+
+        reg.get_probabilities()
+    }
+
+    /// Expected value of the product of the `±1` outcomes.
+    pub fn get_correlation(&self, basis_a: Basis, basis_b: Basis) -> f64 {
+        let &[off_off, off_on, on_off, on_on] = self.get_probabilities(basis_a, basis_b).as_array();
+
+        off_off + on_on - off_on - on_off
+    }
+
+    /// Measures one half (`0` or `1`), collapsing the other half accordingly.
+    pub fn measure_half<R: Rng + ?Sized>(
+        &mut self,
+        half: usize,
+        basis: Basis,
+        rng: &mut R,
+    ) -> bool {
+        self.0.measure(half, basis, rng)
+    }
+
+    pub fn measure<R: Rng + ?Sized>(
+        &mut self,
+        basis_a: Basis,
+        basis_b: Basis,
+        rng: &mut R,
+    ) -> (bool, bool) {
+        (
+            self.measure_half(0, basis_a, rng),
+            self.measure_half(1, basis_b, rng),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::basis::{DEG_0, DEG_45, DEG_90};
+
+    use super::*;
+
+    #[test]
+    fn test_bell_correlations() {
+        assert!((EntangledPair::phi_plus().get_correlation(DEG_45, DEG_45) - 1.0).abs() < 1e-12);
+        assert!((EntangledPair::phi_minus().get_correlation(DEG_0, DEG_0) - 1.0).abs() < 1e-12);
+        assert!((EntangledPair::psi_plus().get_correlation(DEG_0, DEG_0) + 1.0).abs() < 1e-12);
+        assert!((EntangledPair::psi_minus().get_correlation(DEG_45, DEG_45) + 1.0).abs() < 1e-12);
+
+        let angle = 0.3_f64;
+        let e = EntangledPair::phi_plus().get_correlation(DEG_0, Basis::from_radians(angle));
+
+        assert!((e - (2.0 * angle).cos()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_reduced_state() {
+        for pair in [EntangledPair::phi_plus(), EntangledPair::psi_minus()] {
+            for half in [0, 1] {
+                let reduced = pair.get_reduced_state(half);
+
+                // Purity 1/2 only for I / 2
+                assert!((reduced.purity() - 0.5).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_entanglement() {
+        let pair = EntangledPair::psi_plus();
+
+        assert!((pair.get_concurrence() - 1.0).abs() < 1e-9);
+        assert!((pair.get_entanglement_entropy() - 1.0).abs() < 1e-9);
+
+        let mut state = Tensor::<Complex64, 4, 1>::default();
+        state.0[1] = Complex64::new(1.0, 0.0);
+
+        assert!(!pair.is_product_state());
+        assert!(EntangledPair::new(state.clone()).is_product_state());
+        assert!(EntangledPair::new(state.clone()).get_concurrence() < 1e-6);
+        assert!(EntangledPair::new(state).get_entanglement_entropy() < 1e-9);
+    }
+
+    #[test]
+    fn test_measure_is_correlated() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for _ in 0..20 {
+            let (a, b) = EntangledPair::phi_plus().measure(DEG_45, DEG_45, &mut rng);
+            assert_eq!(a, b);
+
+            let (a, b) = EntangledPair::psi_minus().measure(DEG_0, DEG_0, &mut rng);
+            assert_ne!(a, b);
+        }
+    }
+
+    #[test]
+    fn test_measure_half_collapses_other() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for _ in 0..20 {
+            let mut pair = EntangledPair::phi_plus();
+            let a = pair.measure_half(0, DEG_90, &mut rng);
+
+            let &[off_p, on_p] = pair
+                .as_register()
+                .get_marginal_probabilities([1])
+                .as_array();
+
+            // DEG_90 swaps the roles of |0⟩ and |1⟩
+            assert!((if a { off_p } else { on_p } - 1.0).abs() < 1e-12);
+        }
+    }
+}
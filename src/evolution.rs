@@ -0,0 +1,123 @@
+use std::{error::Error, fmt};
+
+use num_complex::Complex64;
+
+use crate::{
+    outer_mul::OuterMul,
+    tensor::{Matrix, Tensor},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZeroStepsError;
+
+impl fmt::Display for ZeroStepsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Trotter evolution needs at least one step")
+    }
+}
+
+impl Error for ZeroStepsError {}
+
+/// Exact propagator `U(t) = e^(-iHt)` for a Hermitian `hamiltonian`, with `ħ = 1`.
+pub fn propagator<const N: usize>(
+    hamiltonian: &Matrix<Complex64, N, N>,
+    t: f64,
+) -> Matrix<Complex64, N, N> {
+    let (values, vectors) = hamiltonian.eigen_hermitian();
+
+    // U = Σ e^(-iλt) |v⟩⟨v|
+    values
+        .0
+        .into_iter()
+        .zip(vectors.0)
+        .map(|(value, v)| {
+            let bra = v.clone().map(|n| n.conj());
+
+            v.outer_mul(bra) * Complex64::cis(-value * t)
+        })
+        .sum()
+}
+
+/// First-order Trotter approximation of `e^(-i(H_1 + H_2 + ...)t)`, applying each
+/// term for `t / steps` in turn, `steps` times. Exact when the terms commute.
+/// Fails if `steps` is 0.
+pub fn trotter_propagator<const N: usize>(
+    terms: &[Matrix<Complex64, N, N>],
+    t: f64,
+    steps: u32,
+) -> Result<Matrix<Complex64, N, N>, ZeroStepsError> {
+    if steps == 0 {
+        return Err(ZeroStepsError);
+    }
+
+    let dt = Complex64::new(0.0, -t / steps as f64);
+
+    let step = terms
+        .iter()
+        .fold(Matrix::<Complex64, N, N>::identity(), |u, h| {
+            (h.clone() * dt).expm() * u
+        });
+
+    Ok(
+        (0..steps).fold(Matrix::<Complex64, N, N>::identity(), |u, _| {
+            step.clone() * u
+        }),
+    )
+}
+
+/// Applies `propagator` to `state`.
+pub fn evolve<const N: usize>(
+    state: Tensor<Complex64, N, 1>,
+    hamiltonian: &Matrix<Complex64, N, N>,
+    t: f64,
+) -> Tensor<Complex64, N, 1> {
+    propagator(hamiltonian, t) * state
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gate::{PAULI_X, PAULI_Y, PAULI_Z};
+
+    use super::*;
+
+    #[test]
+    fn test_propagator_matches_expm() {
+        let h = PAULI_X + PAULI_Z * Complex64::new(0.5, 0.0);
+        let t = 1.7;
+
+        let exact = (h.clone() * Complex64::new(0.0, -t)).expm();
+
+        assert!((propagator(&h, t) - exact).frobenius_norm() < 1e-10);
+    }
+
+    #[test]
+    fn test_trotter_converges() {
+        let terms = [PAULI_X, PAULI_Y * Complex64::new(0.7, 0.0)];
+        let exact = propagator(&(terms[0].clone() + terms[1].clone()), 1.0);
+
+        let coarse =
+            (trotter_propagator(&terms, 1.0, 10).unwrap() - exact.clone()).frobenius_norm();
+        let fine = (trotter_propagator(&terms, 1.0, 1000).unwrap() - exact).frobenius_norm();
+
+        assert!(fine < coarse / 50.0);
+        assert!(fine < 1e-2);
+    }
+
+    #[test]
+    fn test_trotter_zero_steps() {
+        assert_eq!(trotter_propagator(&[PAULI_X], 1.0, 0), Err(ZeroStepsError));
+    }
+
+    #[test]
+    fn test_rabi_oscillation() {
+        let omega = 2.0;
+        let h = PAULI_X * Complex64::new(omega / 2.0, 0.0);
+        let ground = Tensor([Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)]);
+
+        for t in [0.0, 0.4, 1.1, 2.5] {
+            let excited = evolve(ground.clone(), &h, t).0[1].norm_sqr();
+
+            assert!((excited - (omega * t / 2.0).sin().powi(2)).abs() < 1e-12);
+        }
+    }
+}
@@ -1,10 +1,78 @@
-use crate::{basis::Basis, qubit::Qubit};
+use std::{error::Error, fmt};
+
+use num_complex::Complex64;
+use rand::Rng;
+
+use crate::{
+    basis::Basis, density_matrix::DensityMatrix, entangled_pair::EntangledPair, qubit::Qubit,
+    tensor::Tensor,
+};
+
+/// Ways a real polarizer departs from an ideal projection. The default is ideal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Imperfections {
+    /// Ratio of the transmission of the allowed state to that of the blocked one,
+    /// e.g. `1000.0` for a 1000:1 polarizer.
+    pub extinction_ratio: f64,
+    /// Chance that a qubit on its way through is absorbed instead.
+    pub absorption: f64,
+    /// Chance that the detector reports a pass when nothing got through.
+    pub false_pass: f64,
+}
+
+impl Default for Imperfections {
+    fn default() -> Self {
+        Imperfections {
+            extinction_ratio: f64::INFINITY,
+            absorption: 0.0,
+            false_pass: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImperfectionsError {
+    ExtinctionRatioBelowOne,
+    AbsorptionOutOfRange,
+    FalsePassOutOfRange,
+}
+
+impl fmt::Display for ImperfectionsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImperfectionsError::ExtinctionRatioBelowOne => {
+                write!(f, "extinction ratio is below 1")
+            }
+            ImperfectionsError::AbsorptionOutOfRange => write!(f, "absorption is not in [0, 1]"),
+            ImperfectionsError::FalsePassOutOfRange => {
+                write!(f, "false pass chance is not in [0, 1]")
+            }
+        }
+    }
+}
+
+impl Error for ImperfectionsError {}
 
 pub struct Filter {
     basis: Basis,
     allow: bool,
+    imperfections: Imperfections,
+    /// Every reported pass, including leaks and dark counts.
     pub num_passed: u64,
     pub num_total: u64,
+    /// Qubits that would have passed but were absorbed.
+    pub num_absorbed: u64,
+    /// Blocked qubits that leaked through and were reported as passes.
+    pub num_leaked: u64,
+    /// Passes reported when nothing got through.
+    pub num_dark_counts: u64,
+}
+
+/// What a filter let through for one input.
+enum Transmission {
+    Measured,
+    DarkCount,
+    Nothing,
 }
 
 impl Filter {
@@ -12,25 +80,511 @@ impl Filter {
         Filter {
             basis,
             allow,
+            imperfections: Imperfections::default(),
             num_passed: 0,
             num_total: 0,
+            num_absorbed: 0,
+            num_leaked: 0,
+            num_dark_counts: 0,
+        }
+    }
+
+    /// Fails unless `extinction_ratio` is at least 1 and `absorption` and
+    /// `false_pass` are probabilities.
+    pub fn with_imperfections(
+        basis: Basis,
+        allow: bool,
+        imperfections: Imperfections,
+    ) -> Result<Self, ImperfectionsError> {
+        if !(1.0..=f64::INFINITY).contains(&imperfections.extinction_ratio) {
+            return Err(ImperfectionsError::ExtinctionRatioBelowOne);
+        }
+
+        if !(0.0..=1.0).contains(&imperfections.absorption) {
+            return Err(ImperfectionsError::AbsorptionOutOfRange);
+        }
+
+        if !(0.0..=1.0).contains(&imperfections.false_pass) {
+            return Err(ImperfectionsError::FalsePassOutOfRange);
+        }
+
+        Ok(Filter {
+            imperfections,
+            ..Self::new(basis, allow)
+        })
+    }
+
+    pub fn get_basis(&self) -> &Basis {
+        &self.basis
+    }
+
+    /// Whether the `on` or the `off` basis vector is let through.
+    pub fn get_allow(&self) -> bool {
+        self.allow
+    }
+
+    pub fn get_imperfections(&self) -> &Imperfections {
+        &self.imperfections
+    }
+
+    /// The state every properly passing qubit leaves in.
+    pub fn get_pass_state(&self) -> Tensor<Complex64, 2, 1> {
+        self.basis.as_tensor().as_array()[self.allow as usize].clone()
+    }
+
+    /// Exact chance that `input`, a `Qubit` or a `DensityMatrix`, is reported as
+    /// passing, imperfections included.
+    pub fn predicted_pass_probability<S: Into<DensityMatrix<2>>>(&self, input: S) -> f64 {
+        self.predict(&input.into()).0
+    }
+
+    /// Standard errors by which the observed pass fraction differs from `predicted`.
+    pub fn get_deviation(&self, predicted: f64) -> f64 {
+        deviation(self.num_passed, self.num_total, predicted)
+    }
+
+    pub fn filter<R: Rng + ?Sized>(&mut self, mut qb: Qubit, rng: &mut R) -> Option<Qubit> {
+        let allowed = qb.measure(self.basis.clone(), rng) == self.allow;
+
+        match self.transmit(allowed, rng) {
+            Transmission::Measured => Some(qb),
+            Transmission::DarkCount => {
+                let [a, b] = self.get_pass_state().0;
+
+                Some(Qubit::new(a, b))
+            }
+            Transmission::Nothing => None,
         }
     }
 
-    pub fn filter(&mut self, mut qb: Qubit) -> Option<Qubit> {
+    /// Like `filter`, for a possibly mixed state. A passing state leaves pure.
+    pub fn filter_mixed<R: Rng + ?Sized>(
+        &mut self,
+        mut rho: DensityMatrix<2>,
+        rng: &mut R,
+    ) -> Option<DensityMatrix<2>> {
+        let allowed = rho.measure(self.basis.clone(), rng) == self.allow;
+
+        match self.transmit(allowed, rng) {
+            Transmission::Measured => Some(rho),
+            Transmission::DarkCount => Some(DensityMatrix::from_pure(self.get_pass_state())),
+            Transmission::Nothing => None,
+        }
+    }
+
+    /// Sends one half of `pair` through the filter. The other half collapses to
+    /// match whatever this half was measured as, whether or not it passed.
+    pub fn filter_half<R: Rng + ?Sized>(
+        &mut self,
+        pair: &mut EntangledPair,
+        half: usize,
+        rng: &mut R,
+    ) -> bool {
+        let allowed = pair.measure_half(half, self.basis.clone(), rng) == self.allow;
+
+        !matches!(self.transmit(allowed, rng), Transmission::Nothing)
+    }
+
+    pub fn reset(&mut self) {
+        self.num_passed = 0;
+        self.num_total = 0;
+        self.num_absorbed = 0;
+        self.num_leaked = 0;
+        self.num_dark_counts = 0;
+    }
+
+    /// Applies the imperfections to a qubit already measured as `allowed` or not,
+    /// and updates the counters.
+    fn transmit<R: Rng + ?Sized>(&mut self, allowed: bool, rng: &mut R) -> Transmission {
         self.num_total += 1;
 
-        if qb.measure(self.basis.clone()) == self.allow {
+        let leaked = !allowed && chance(rng, self.imperfections.extinction_ratio.recip());
+
+        if allowed || leaked {
+            if chance(rng, self.imperfections.absorption) {
+                self.num_absorbed += 1;
+            } else {
+                if leaked {
+                    self.num_leaked += 1;
+                }
+
+                self.num_passed += 1;
+
+                return Transmission::Measured;
+            }
+        }
+
+        if chance(rng, self.imperfections.false_pass) {
+            self.num_dark_counts += 1;
             self.num_passed += 1;
 
-            Some(qb)
-        } else {
-            None
+            return Transmission::DarkCount;
         }
+
+        Transmission::Nothing
+    }
+
+    /// Chance that `input` is reported as passing, and the state it leaves in if so.
+    fn predict(&self, input: &DensityMatrix<2>) -> (f64, DensityMatrix<2>) {
+        let pass = self.get_pass_state();
+        let block = self.basis.as_tensor().as_array()[!self.allow as usize].clone();
+
+        let survive = 1.0 - self.imperfections.absorption;
+        let allowed = survive * input.fidelity(&pass);
+        let leaked = survive * input.fidelity(&block) / self.imperfections.extinction_ratio;
+        let dark = (1.0 - allowed - leaked) * self.imperfections.false_pass;
+
+        let p = allowed + leaked + dark;
+
+        if p <= 0.0 {
+            return (0.0, DensityMatrix::from_pure(pass));
+        }
+
+        let output =
+            DensityMatrix::from_ensemble(&[((allowed + dark) / p, pass), (leaked / p, block)]);
+
+        (p, output)
+    }
+}
+
+fn chance<R: Rng + ?Sized>(rng: &mut R, p: f64) -> bool {
+    // Skipping the draw keeps ideal filters on the same random stream
+    p > 0.0 && rng.gen_bool(p.min(1.0))
+}
+
+/// Filters applied one after another. A qubit stops at the first stage it fails.
+pub struct FilterChain {
+    filters: Vec<Filter>,
+    pub num_passed: u64,
+    pub num_total: u64,
+}
+
+impl FilterChain {
+    pub fn new(filters: Vec<Filter>) -> Self {
+        FilterChain {
+            filters,
+            num_passed: 0,
+            num_total: 0,
+        }
+    }
+
+    pub fn push(&mut self, filter: Filter) {
+        self.filters.push(filter);
+    }
+
+    /// The stages in order, each with its own pass counts.
+    pub fn get_filters(&self) -> &[Filter] {
+        &self.filters
+    }
+
+    pub fn filter<R: Rng + ?Sized>(&mut self, qb: Qubit, rng: &mut R) -> Option<Qubit> {
+        self.num_total += 1;
+
+        let qb = self
+            .filters
+            .iter_mut()
+            .try_fold(qb, |qb, filter| filter.filter(qb, rng))?;
+
+        self.num_passed += 1;
+
+        Some(qb)
+    }
+
+    /// Sends every qubit of `qubits` through the chain, returning how many passed.
+    pub fn run<I, R>(&mut self, qubits: I, rng: &mut R) -> u64
+    where
+        I: IntoIterator<Item = Qubit>,
+        R: Rng + ?Sized,
+    {
+        qubits
+            .into_iter()
+            .filter_map(|qb| self.filter(qb, rng))
+            .count() as u64
+    }
+
+    /// Fraction of qubits that made it through every stage. Panics if no qubits
+    /// have been sent through, like `Counts::frequency`.
+    pub fn get_transmission(&self) -> f64 {
+        assert!(
+            self.num_total > 0,
+            "no qubits to estimate a transmission from"
+        );
+
+        self.num_passed as f64 / self.num_total as f64
+    }
+
+    /// Exact chance that `input` makes it through every stage, following the
+    /// state each stage leaves in. For ideal filters each stage after the first
+    /// passes `|⟨a|b⟩|²` of the previous stage's output, `cos²` of the angle
+    /// between linear polarizers.
+    pub fn predicted_pass_probability<S: Into<DensityMatrix<2>>>(&self, input: S) -> f64 {
+        self.filters
+            .iter()
+            .fold((1.0, input.into()), |(p, rho), filter| {
+                let (pass, output) = filter.predict(&rho);
+
+                (p * pass, output)
+            })
+            .0
+    }
+
+    /// Predicted transmission of unpolarized input, imperfections included. For
+    /// ideal filters this is Malus's law.
+    pub fn get_unpolarized_transmission(&self) -> f64 {
+        self.predicted_pass_probability(DensityMatrix::maximally_mixed())
+    }
+
+    /// Standard errors by which the observed transmission differs from `predicted`.
+    pub fn get_deviation(&self, predicted: f64) -> f64 {
+        deviation(self.num_passed, self.num_total, predicted)
     }
 
     pub fn reset(&mut self) {
         self.num_passed = 0;
         self.num_total = 0;
+
+        for filter in &mut self.filters {
+            filter.reset();
+        }
+    }
+}
+
+/// Signed z-score of `passed` out of `total` against a binomial with probability
+/// `predicted`. Infinite when a certain outcome was contradicted.
+fn deviation(passed: u64, total: u64, predicted: f64) -> f64 {
+    let difference = passed as f64 - predicted * total as f64;
+
+    if difference == 0.0 {
+        return 0.0;
+    }
+
+    difference / (total as f64 * predicted * (1.0 - predicted)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use std::f64::consts::{FRAC_PI_6, PI};
+
+    use crate::basis::{DEG_0, DEG_45, DEG_90};
+
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "no qubits")]
+    fn test_empty_transmission() {
+        FilterChain::new(vec![Filter::new(DEG_0, true)]).get_transmission();
+    }
+
+    #[test]
+    fn test_chain_counts() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut chain = FilterChain::new(vec![
+            Filter::new(DEG_0, true),
+            Filter::new(DEG_45, true),
+            Filter::new(DEG_90, true),
+        ]);
+
+        let qubits: Vec<_> = (0..10_000).map(|_| Qubit::random(&mut rng)).collect();
+        let passed = chain.run(qubits, &mut rng);
+
+        let stages = chain.get_filters();
+
+        assert_eq!(chain.num_total, 10_000);
+        assert_eq!(chain.num_passed, passed);
+        assert_eq!(stages[0].num_total, 10_000);
+        assert_eq!(stages[1].num_total, stages[0].num_passed);
+        assert_eq!(stages[2].num_total, stages[1].num_passed);
+        assert_eq!(stages[2].num_passed, passed);
+
+        assert!((chain.get_unpolarized_transmission() - 0.125).abs() < 1e-12);
+        assert!((chain.get_transmission() - 0.125).abs() < 0.01);
+
+        chain.reset();
+
+        assert_eq!(chain.get_filters()[1].num_total, 0);
+    }
+
+    #[test]
+    fn test_crossed_polarizers() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut chain = FilterChain::new(vec![Filter::new(DEG_0, true), Filter::new(DEG_90, true)]);
+
+        for _ in 0..1000 {
+            assert!(chain.filter(Qubit::random(&mut rng), &mut rng).is_none());
+        }
+
+        assert_eq!(chain.get_unpolarized_transmission(), 0.0);
+    }
+
+    #[test]
+    fn test_predicted_pass_probability() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut filter = Filter::new(DEG_45, true);
+        let qb = Qubit::from_bloch(FRAC_PI_6, 0.0);
+
+        // Bloch angle θ from |0⟩ is a polarization angle of θ / 2, so cos²(π/12 - π/4)
+        let predicted = filter.predicted_pass_probability(&qb);
+
+        assert!((predicted - (PI / 12.0 - PI / 4.0).cos().powi(2)).abs() < 1e-12);
+
+        for _ in 0..10_000 {
+            filter.filter(Qubit::from_bloch(FRAC_PI_6, 0.0), &mut rng);
+        }
+
+        assert!(filter.get_deviation(predicted).abs() < 3.0);
+        assert!(filter.get_deviation(0.5).abs() > 3.0);
+    }
+
+    #[test]
+    fn test_chain_prediction() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut chain =
+            FilterChain::new(vec![Filter::new(DEG_45, true), Filter::new(DEG_90, true)]);
+        let qb = Qubit::from_bloch(0.0, 0.0);
+
+        // |0⟩ passes 45° with 1/2 and 90° (on = |0⟩) then with 1/2
+        let predicted = chain.predicted_pass_probability(&qb);
+
+        assert!((predicted - 0.25).abs() < 1e-12);
+        assert_eq!(
+            FilterChain::new(vec![]).predicted_pass_probability(&qb),
+            1.0
+        );
+
+        for _ in 0..10_000 {
+            chain.filter(Qubit::from_bloch(0.0, 0.0), &mut rng);
+        }
+
+        assert!(chain.get_deviation(predicted).abs() < 3.0);
+        assert_eq!(chain.get_deviation(0.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_extinction_ratio() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let imperfections = Imperfections {
+            extinction_ratio: 10.0,
+            ..Default::default()
+        };
+        let mut filter = Filter::with_imperfections(DEG_0, true, imperfections).unwrap();
+        let blocked = Qubit::new(Complex64::new(1.0, 0.0), Complex64::default());
+
+        assert!((filter.predicted_pass_probability(&blocked) - 0.1).abs() < 1e-12);
+
+        for _ in 0..10_000 {
+            if let Some(qb) = filter.filter(
+                Qubit::new(Complex64::new(1.0, 0.0), Complex64::default()),
+                &mut rng,
+            ) {
+                // Leaked qubits keep the blocked polarization
+                assert_eq!(qb.get_state(), blocked.get_state());
+            }
+        }
+
+        assert_eq!(filter.num_leaked, filter.num_passed);
+        assert!(filter.get_deviation(0.1).abs() < 3.0);
+    }
+
+    #[test]
+    fn test_absorption_and_dark_counts() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let imperfections = Imperfections {
+            absorption: 0.2,
+            false_pass: 0.05,
+            ..Default::default()
+        };
+        let mut filter = Filter::with_imperfections(DEG_45, true, imperfections).unwrap();
+        let qb = Qubit::from_bloch(FRAC_PI_6, 0.0);
+
+        // (1 - a) p + (1 - (1 - a) p) f
+        let p = Filter::new(DEG_45, true).predicted_pass_probability(&qb);
+        let transmitted = 0.8 * p;
+        let predicted = filter.predicted_pass_probability(&qb);
+
+        assert!((predicted - (transmitted + (1.0 - transmitted) * 0.05)).abs() < 1e-12);
+
+        for _ in 0..10_000 {
+            filter.filter(Qubit::from_bloch(FRAC_PI_6, 0.0), &mut rng);
+        }
+
+        let Filter {
+            num_passed,
+            num_total,
+            num_absorbed,
+            num_leaked,
+            num_dark_counts,
+            ..
+        } = filter;
+
+        assert_eq!(num_leaked, 0);
+        assert!(((num_absorbed as f64 / num_total as f64) - 0.2 * p).abs() < 0.02);
+        assert!(
+            ((num_dark_counts as f64 / num_total as f64) - (1.0 - transmitted) * 0.05).abs() < 0.01
+        );
+        assert!(deviation(num_passed, num_total, predicted).abs() < 3.0);
+    }
+
+    #[test]
+    fn test_leaky_crossed_polarizers() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let leaky = Imperfections {
+            extinction_ratio: 100.0,
+            ..Default::default()
+        };
+        let mut chain = FilterChain::new(vec![
+            Filter::with_imperfections(DEG_0, true, leaky).unwrap(),
+            Filter::with_imperfections(DEG_90, true, leaky).unwrap(),
+        ]);
+
+        // Pass then leak, 0.5 · 0.01, plus leak then pass, 0.005 · 1
+        assert!((chain.get_unpolarized_transmission() - 0.01).abs() < 1e-12);
+
+        for _ in 0..100_000 {
+            chain.filter(Qubit::random(&mut rng), &mut rng);
+        }
+
+        assert!(chain.num_passed > 0);
+        assert!(chain.get_deviation(0.01).abs() < 3.0);
+    }
+
+    #[test]
+    fn test_invalid_imperfections() {
+        for (imperfections, error) in [
+            (
+                Imperfections {
+                    extinction_ratio: 0.0,
+                    ..Default::default()
+                },
+                ImperfectionsError::ExtinctionRatioBelowOne,
+            ),
+            (
+                Imperfections {
+                    extinction_ratio: f64::NAN,
+                    ..Default::default()
+                },
+                ImperfectionsError::ExtinctionRatioBelowOne,
+            ),
+            (
+                Imperfections {
+                    absorption: 1.5,
+                    ..Default::default()
+                },
+                ImperfectionsError::AbsorptionOutOfRange,
+            ),
+            (
+                Imperfections {
+                    false_pass: -0.1,
+                    ..Default::default()
+                },
+                ImperfectionsError::FalsePassOutOfRange,
+            ),
+        ] {
+            assert_eq!(
+                Filter::with_imperfections(DEG_0, true, imperfections).err(),
+                Some(error)
+            );
+        }
     }
 }
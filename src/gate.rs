@@ -0,0 +1,283 @@
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+use num_complex::Complex64;
+
+use crate::tensor::{Matrix, Tensor};
+
+/// Operator on `N` amplitudes.
+pub type Gate<const N: usize> = Matrix<Complex64, N, N>;
+
+const ZERO: Complex64 = Complex64::new(0.0, 0.0);
+const ONE: Complex64 = Complex64::new(1.0, 0.0);
+const I: Complex64 = Complex64::new(0.0, 1.0);
+
+pub const IDENTITY: Gate<2> = Tensor([Tensor([ONE, ZERO]), Tensor([ZERO, ONE])]);
+pub const PAULI_X: Gate<2> = Tensor([Tensor([ZERO, ONE]), Tensor([ONE, ZERO])]);
+pub const PAULI_Y: Gate<2> = Tensor([Tensor([ZERO, Complex64::new(0.0, -1.0)]), Tensor([I, ZERO])]);
+pub const PAULI_Z: Gate<2> = Tensor([
+    Tensor([ONE, ZERO]),
+    Tensor([ZERO, Complex64::new(-1.0, 0.0)]),
+]);
+pub const HADAMARD: Gate<2> = Tensor([
+    Tensor([
+        Complex64::new(FRAC_1_SQRT_2, 0.0),
+        Complex64::new(FRAC_1_SQRT_2, 0.0),
+    ]),
+    Tensor([
+        Complex64::new(FRAC_1_SQRT_2, 0.0),
+        Complex64::new(-FRAC_1_SQRT_2, 0.0),
+    ]),
+]);
+pub const S: Gate<2> = Tensor([Tensor([ONE, ZERO]), Tensor([ZERO, I])]);
+pub const T: Gate<2> = Tensor([
+    Tensor([ONE, ZERO]),
+    Tensor([ZERO, Complex64::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2)]),
+]);
+
+/// Flips the second qubit when the first is `|1⟩`.
+pub const CNOT: Gate<4> = Tensor([
+    Tensor([ONE, ZERO, ZERO, ZERO]),
+    Tensor([ZERO, ONE, ZERO, ZERO]),
+    Tensor([ZERO, ZERO, ZERO, ONE]),
+    Tensor([ZERO, ZERO, ONE, ZERO]),
+]);
+pub const CZ: Gate<4> = Tensor([
+    Tensor([ONE, ZERO, ZERO, ZERO]),
+    Tensor([ZERO, ONE, ZERO, ZERO]),
+    Tensor([ZERO, ZERO, ONE, ZERO]),
+    Tensor([ZERO, ZERO, ZERO, Complex64::new(-1.0, 0.0)]),
+]);
+pub const SWAP: Gate<4> = Tensor([
+    Tensor([ONE, ZERO, ZERO, ZERO]),
+    Tensor([ZERO, ZERO, ONE, ZERO]),
+    Tensor([ZERO, ONE, ZERO, ZERO]),
+    Tensor([ZERO, ZERO, ZERO, ONE]),
+]);
+pub const ISWAP: Gate<4> = Tensor([
+    Tensor([ONE, ZERO, ZERO, ZERO]),
+    Tensor([ZERO, ZERO, I, ZERO]),
+    Tensor([ZERO, I, ZERO, ZERO]),
+    Tensor([ZERO, ZERO, ZERO, ONE]),
+]);
+
+/// Rotation by `theta` about the Bloch sphere's x axis.
+pub fn rx(theta: f64) -> Gate<2> {
+    let (sin, cos) = (theta / 2.0).sin_cos();
+
+    Tensor([
+        Tensor([cos.into(), Complex64::new(0.0, -sin)]),
+        Tensor([Complex64::new(0.0, -sin), cos.into()]),
+    ])
+}
+
+/// Rotation by `theta` about the Bloch sphere's y axis.
+pub fn ry(theta: f64) -> Gate<2> {
+    let (sin, cos) = (theta / 2.0).sin_cos();
+
+    Tensor([
+        Tensor([cos.into(), (-sin).into()]),
+        Tensor([sin.into(), cos.into()]),
+    ])
+}
+
+/// Rotation by `theta` about the Bloch sphere's z axis.
+pub fn rz(theta: f64) -> Gate<2> {
+    Tensor([
+        Tensor([Complex64::cis(-theta / 2.0), ZERO]),
+        Tensor([ZERO, Complex64::cis(theta / 2.0)]),
+    ])
+}
+
+/// General single-qubit unitary, `Rz(phi) Ry(theta) Rz(lambda)` up to global phase.
+pub fn u3(theta: f64, phi: f64, lambda: f64) -> Gate<2> {
+    let (sin, cos) = (theta / 2.0).sin_cos();
+
+    Tensor([
+        Tensor([cos.into(), -Complex64::cis(lambda) * sin]),
+        Tensor([
+            Complex64::cis(phi) * sin,
+            Complex64::cis(phi + lambda) * cos,
+        ]),
+    ])
+}
+
+/// Shifts the phase of `|1⟩` by `phi`.
+pub fn phase(phi: f64) -> Gate<2> {
+    Tensor([Tensor([ONE, ZERO]), Tensor([ZERO, Complex64::cis(phi)])])
+}
+
+/// Applies `u` to the second qubit when the first is `|1⟩`.
+pub fn controlled(u: &Gate<2>) -> Gate<4> {
+    let [[a, b], [c, d]] = u.as_array().clone().map(|row| row.0);
+
+    Tensor([
+        Tensor([ONE, ZERO, ZERO, ZERO]),
+        Tensor([ZERO, ONE, ZERO, ZERO]),
+        Tensor([ZERO, ZERO, a, b]),
+        Tensor([ZERO, ZERO, c, d]),
+    ])
+}
+
+/// Generalized Pauli X, `|k⟩ → |k + 1 mod D⟩`.
+pub fn shift<const D: usize>() -> Gate<D> {
+    from_fn(|i, j| if i == (j + 1) % D { ONE } else { ZERO })
+}
+
+/// Generalized Pauli Z, `|k⟩ → ω^k |k⟩` with `ω = e^(2πi / D)`.
+pub fn clock<const D: usize>() -> Gate<D> {
+    from_fn(|i, j| {
+        if i == j {
+            Complex64::cis(2.0 * PI * i as f64 / D as f64)
+        } else {
+            ZERO
+        }
+    })
+}
+
+/// Spin-`j` x component for `D = 2j + 1`, in the `|m = j⟩, ..., |m = -j⟩` basis.
+pub fn spin_x<const D: usize>() -> Gate<D> {
+    from_fn(|i, j| ((ladder::<D>(i, j) + ladder::<D>(j, i)) * 0.5).into())
+}
+
+/// Spin-`j` y component for `D = 2j + 1`, in the `|m = j⟩, ..., |m = -j⟩` basis.
+pub fn spin_y<const D: usize>() -> Gate<D> {
+    from_fn(|i, j| (ladder::<D>(i, j) - ladder::<D>(j, i)) * Complex64::new(0.0, -0.5))
+}
+
+/// Spin-`j` z component for `D = 2j + 1`, in the `|m = j⟩, ..., |m = -j⟩` basis.
+pub fn spin_z<const D: usize>() -> Gate<D> {
+    from_fn(|i, j| {
+        if i == j {
+            Complex64::new((D - 1) as f64 / 2.0 - i as f64, 0.0)
+        } else {
+            ZERO
+        }
+    })
+}
+
+/// `⟨i|J+|j⟩`, nonzero only for `i + 1 = j`.
+fn ladder<const D: usize>(i: usize, j: usize) -> f64 {
+    if i + 1 != j {
+        return 0.0;
+    }
+
+    let spin = (D - 1) as f64 / 2.0;
+    let m = spin - j as f64;
+
+    (spin * (spin + 1.0) - m * (m + 1.0)).sqrt()
+}
+
+fn from_fn<const D: usize>(mut f: impl FnMut(usize, usize) -> Complex64) -> Gate<D> {
+    let mut i = 0;
+
+    Tensor([(); D].map(|_| {
+        let mut j = 0;
+
+        let row = [(); D].map(|_| {
+            let n = f(i, j);
+
+            j += 1;
+
+            n
+        });
+
+        i += 1;
+
+        Tensor(row)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::{qubit::Qubit, register::Register};
+
+    use super::*;
+
+    fn assert_gate_eq<const N: usize>(a: &Gate<N>, b: &Gate<N>) {
+        for (row_a, row_b) in a.as_array().iter().zip(b.as_array()) {
+            for (x, y) in row_a.as_array().iter().zip(row_b.as_array()) {
+                assert!((x - y).norm() < 1e-12, "{:?} != {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pauli_x_flips() {
+        let mut qb = Qubit::new(ONE, ZERO);
+
+        qb.apply(&PAULI_X);
+
+        assert_eq!(qb.get_state(), &Tensor([ZERO, ONE]));
+    }
+
+    #[test]
+    fn test_hadamard_is_involution() {
+        let mut qb = Qubit::new(Complex64::new(0.6, 0.0), Complex64::new(0.0, 0.8));
+
+        qb.apply(&HADAMARD);
+        qb.apply(&HADAMARD);
+
+        let [a, b] = qb.get_state().0;
+        assert!((a - 0.6).norm() < 1e-12 && (b - I * 0.8).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_rotations() {
+        let minus_i = Complex64::new(0.0, -1.0);
+
+        assert_gate_eq(&rx(PI), &PAULI_X.map(|row| row * minus_i));
+        assert_gate_eq(&ry(PI), &PAULI_Y.map(|row| row * minus_i));
+        assert_gate_eq(&rz(PI), &PAULI_Z.map(|row| row * minus_i));
+        assert_gate_eq(&u3(PI / 2.0, 0.0, PI), &HADAMARD);
+        assert_gate_eq(&phase(PI / 2.0), &S);
+        assert_gate_eq(&phase(PI / 4.0), &T);
+    }
+
+    #[test]
+    fn test_controlled() {
+        assert_gate_eq(&controlled(&PAULI_X), &CNOT);
+        assert_gate_eq(&controlled(&PAULI_Z), &CZ);
+    }
+
+    #[test]
+    fn test_qubit_generalizations() {
+        assert_gate_eq(&shift::<2>(), &PAULI_X);
+        assert_gate_eq(&clock::<2>(), &PAULI_Z);
+        assert_gate_eq(&spin_x::<2>(), &PAULI_X.map(|row| row * 0.5));
+        assert_gate_eq(&spin_y::<2>(), &PAULI_Y.map(|row| row * 0.5));
+        assert_gate_eq(&spin_z::<2>(), &PAULI_Z.map(|row| row * 0.5));
+    }
+
+    #[test]
+    fn test_clock_shift_commutation() {
+        let omega = Complex64::cis(2.0 * PI / 3.0);
+
+        assert_gate_eq(
+            &(clock::<3>() * shift::<3>()),
+            &(shift::<3>() * clock::<3>()).map(|row| row * omega),
+        );
+    }
+
+    #[test]
+    fn test_spin_1_commutator() {
+        let (x, y, z) = (spin_x::<3>(), spin_y::<3>(), spin_z::<3>());
+
+        assert_gate_eq(&(x.clone() * y.clone() - y * x), &z.map(|row| row * I));
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut reg = Register::<2>::new();
+
+        reg.apply(&PAULI_X, [0]);
+        reg.apply(&SWAP, [0, 1]);
+
+        assert_eq!(reg.get_probabilities(), Tensor([0.0, 1.0, 0.0, 0.0]));
+
+        reg.apply(&ISWAP, [0, 1]);
+
+        assert_eq!(reg.get_state().0[2], I);
+    }
+}
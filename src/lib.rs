@@ -2,9 +2,22 @@
 #![feature(generic_const_exprs)]
 
 pub mod basis;
+pub mod braket;
+pub mod channel;
+pub mod density_matrix;
 pub mod entangled_pair;
+pub mod evolution;
 pub mod filter;
+pub mod gate;
+pub mod linalg;
+pub mod outer_mul;
 pub mod qubit;
+pub mod qudit;
+pub mod register;
+pub mod sampling;
+pub mod scalar;
+pub mod schmidt;
 pub mod tensor;
+pub mod tensor_mul;
 pub mod transposable;
-pub mod outer_mul;
+mod util;
@@ -0,0 +1,549 @@
+use std::{array, error::Error, fmt};
+
+use num_traits::{Float, NumCast, One, Zero};
+
+use crate::{
+    scalar::Scalar,
+    tensor::{Matrix, Tensor},
+    transposable::Transposable,
+};
+
+const MAX_SWEEPS: usize = 100;
+const MAX_TERMS: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SingularMatrixError;
+
+impl fmt::Display for SingularMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "matrix is singular")
+    }
+}
+
+impl Error for SingularMatrixError {}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Scalar,
+{
+    pub fn identity() -> Self {
+        let mut idx = 0;
+
+        Tensor([(); N].map(|_| {
+            let mut row = Tensor([T::zero(); N]);
+            row.0[idx] = T::one();
+
+            idx += 1;
+
+            row
+        }))
+    }
+
+    pub fn trace(&self) -> T {
+        self.as_array()
+            .iter()
+            .enumerate()
+            .map(|(i, row)| row.as_array()[i])
+            .sum()
+    }
+
+    /// Computed by Gaussian elimination with partial pivoting.
+    pub fn determinant(&self) -> T {
+        let mut rows = self.clone().0.map(|row| row.0);
+        let mut det = T::one();
+
+        for col in 0..N {
+            let pivot = pivot_row(&rows, col);
+
+            if rows[pivot][col].is_zero() {
+                return T::zero();
+            }
+
+            if pivot != col {
+                rows.swap(pivot, col);
+                det = -det;
+            }
+
+            let pivot = rows[col];
+            det = det * pivot[col];
+
+            for row in rows.iter_mut().skip(col + 1) {
+                let factor = row[col] / pivot[col];
+
+                for (a, b) in row.iter_mut().zip(pivot).skip(col) {
+                    *a = *a - factor * b;
+                }
+            }
+        }
+
+        det
+    }
+
+    /// Computed by Gauss-Jordan elimination. Fails when a pivot vanishes to within
+    /// the scalar type's rounding error, so exact types only fail on true singularity.
+    pub fn inverse(&self) -> Result<Self, SingularMatrixError> {
+        let mut rows = self.clone().0.map(|row| row.0);
+        let mut inv = Self::identity().0.map(|row| row.0);
+
+        let scale = rows
+            .iter()
+            .flatten()
+            .map(|n| n.norm_sqr())
+            .fold(T::Real::zero(), |a, b| if b > a { b } else { a });
+        let n: T::Real = (0..N).map(|_| T::Real::one()).sum();
+        let tolerance = T::epsilon() * T::epsilon() * n * n * scale;
+
+        for col in 0..N {
+            let pivot = pivot_row(&rows, col);
+
+            if rows[pivot][col].norm_sqr() <= tolerance {
+                return Err(SingularMatrixError);
+            }
+
+            rows.swap(pivot, col);
+            inv.swap(pivot, col);
+
+            let p = rows[col][col];
+
+            rows[col] = rows[col].map(|n| n / p);
+            inv[col] = inv[col].map(|n| n / p);
+
+            let (pivot, pivot_inv) = (rows[col], inv[col]);
+
+            for (row, (a, a_inv)) in rows.iter_mut().zip(inv.iter_mut()).enumerate() {
+                if row == col {
+                    continue;
+                }
+
+                let factor = a[col];
+
+                for (n, b) in a.iter_mut().zip(pivot) {
+                    *n = *n - factor * b;
+                }
+
+                for (n, b) in a_inv.iter_mut().zip(pivot_inv) {
+                    *n = *n - factor * b;
+                }
+            }
+        }
+
+        Ok(Tensor(inv.map(Tensor)))
+    }
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    T: Scalar,
+    T::Real: Float,
+{
+    /// `√(Σ |a_ij|²)`
+    pub fn frobenius_norm(&self) -> T::Real {
+        self.as_array()
+            .iter()
+            .flat_map(|row| row.as_array())
+            .map(|n| n.norm_sqr())
+            .sum::<T::Real>()
+            .sqrt()
+    }
+
+    /// Largest singular value, `√λ_max(A†A)`.
+    pub fn operator_norm(&self) -> T::Real {
+        let (values, _) = (self.T() * self.clone()).eigen_hermitian();
+
+        values.as_array()[M - 1].max(T::Real::zero()).sqrt()
+    }
+
+    /// Singular value decomposition, `A = Σ_k σ_k |u_k⟩⟨v_k|`.
+    ///
+    /// Returns the `u_k` as the rows of an `N × N` matrix, the `σ_k` in descending
+    /// order, and the `v_k` as the rows of an `M × M` matrix. Values past
+    /// `min(N, M)` are zero, and both sets of rows are completed to orthonormal
+    /// bases.
+    pub fn svd(&self) -> (Matrix<T, N, N>, Tensor<T::Real, N, 1>, Matrix<T, M, M>) {
+        let a_dagger = self.T();
+        let (_, vectors) = (self.clone() * a_dagger.clone()).eigen_hermitian();
+
+        // Largest singular value first
+        let mut u = vectors.0;
+        u.reverse();
+
+        // `A†u_k = σ_k v_k` recovers small `σ_k` more accurately than `√λ_k`
+        let tolerance = T::epsilon().sqrt() * self.frobenius_norm();
+        let mut v: Vec<[T; M]> = Vec::with_capacity(M);
+
+        let s = array::from_fn(|k| {
+            let w = a_dagger.clone() * u[k].clone();
+            let sigma = Tensor([w.clone()]).frobenius_norm();
+
+            if k == v.len() && k < M && sigma > tolerance {
+                v.push(w.0.map(|n| n / T::from_real(sigma)));
+            }
+
+            sigma
+        });
+
+        while v.len() < M {
+            let residuals = (0..M).map(|j| {
+                let mut e = [T::zero(); M];
+                e[j] = T::one();
+
+                for row in &v {
+                    let overlap = row[j].conj();
+
+                    for (x, r) in e.iter_mut().zip(row) {
+                        *x = *x - *r * overlap;
+                    }
+                }
+
+                e
+            });
+
+            let norm_sqr = |e: &[T; M]| e.iter().map(|n| n.norm_sqr()).sum::<T::Real>();
+            let best = residuals
+                .max_by(|a, b| norm_sqr(a).partial_cmp(&norm_sqr(b)).unwrap())
+                .unwrap();
+            let norm = norm_sqr(&best).sqrt();
+
+            v.push(best.map(|n| n / T::from_real(norm)));
+        }
+
+        (
+            Tensor(u),
+            Tensor(s),
+            Tensor(array::from_fn(|k| Tensor(v[k]))),
+        )
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Scalar,
+    T::Real: Float,
+{
+    /// Eigen-decomposition of a Hermitian matrix by cyclic Jacobi rotations.
+    ///
+    /// Returns the eigenvalues in ascending order, and a matrix whose rows are the
+    /// matching orthonormal eigenvectors (the layout `Basis` uses). Only the
+    /// Hermitian part `(A + A†) / 2` of `self` is considered.
+    pub fn eigen_hermitian(&self) -> (Tensor<T::Real, N, 1>, Matrix<T, N, N>) {
+        let half = (T::Real::one() + T::Real::one()).recip();
+        let hermitian = (self.clone() + self.T()) * T::from_real(half);
+
+        let mut a = hermitian.0.map(|row| row.0);
+        let mut v = Self::identity().0.map(|row| row.0);
+
+        let total: T::Real = a.iter().flatten().map(|n| n.norm_sqr()).sum();
+        let tolerance = T::epsilon() * T::epsilon() * total;
+
+        for _ in 0..MAX_SWEEPS {
+            let off: T::Real = (0..N)
+                .flat_map(|p| (p + 1..N).map(move |q| (p, q)))
+                .map(|(p, q)| a[p][q].norm_sqr())
+                .sum();
+
+            if off <= tolerance {
+                break;
+            }
+
+            for p in 0..N {
+                for q in p + 1..N {
+                    jacobi_rotate(&mut a, &mut v, p, q);
+                }
+            }
+        }
+
+        let mut order: [usize; N] = array::from_fn(|i| i);
+        order.sort_by(|&i, &j| a[i][i].re().partial_cmp(&a[j][j].re()).unwrap());
+
+        // Eigenvectors are the columns of `v`
+        (
+            Tensor(order.map(|i| a[i][i].re())),
+            Tensor(order.map(|i| Tensor(v.map(|row| row[i])))),
+        )
+    }
+
+    /// Matrix exponential `e^A`, by scaling and squaring a truncated Taylor series.
+    pub fn expm(&self) -> Self {
+        let two = T::Real::one() + T::Real::one();
+
+        let mut squarings = 0;
+        let mut norm = self.frobenius_norm();
+
+        while norm > two.recip() {
+            norm = norm / two;
+            squarings += 1;
+        }
+
+        let a = self.clone() * T::from_real(two.powi(-squarings));
+        let mut term = Self::identity();
+        let mut sum = Self::identity();
+
+        for k in 1..MAX_TERMS {
+            let k = <T::Real as NumCast>::from(k).unwrap();
+
+            term = term * a.clone() * T::from_real(k.recip());
+            sum = sum + term.clone();
+
+            if term.frobenius_norm() <= T::epsilon() * sum.frobenius_norm() {
+                break;
+            }
+        }
+
+        for _ in 0..squarings {
+            sum = sum.clone() * sum;
+        }
+
+        sum
+    }
+}
+
+/// Applies the unitary `G` zeroing `a[p][q]`, as `a ← G†aG` and `v ← vG`.
+fn jacobi_rotate<T, const N: usize>(a: &mut [[T; N]; N], v: &mut [[T; N]; N], p: usize, q: usize)
+where
+    T: Scalar,
+    T::Real: Float,
+{
+    let abs = a[p][q].norm_sqr().sqrt();
+
+    if abs.is_zero() {
+        return;
+    }
+
+    // Rotating column q by the conjugate phase makes a[p][q] real and positive,
+    // after which the real Jacobi rotation applies
+    let phase = a[p][q] / T::from_real(abs);
+    let two = T::Real::one() + T::Real::one();
+
+    let tau = (a[q][q].re() - a[p][p].re()) / (two * abs);
+    let t = if tau >= T::Real::zero() {
+        (tau + (T::Real::one() + tau * tau).sqrt()).recip()
+    } else {
+        -(-tau + (T::Real::one() + tau * tau).sqrt()).recip()
+    };
+    let c = (T::Real::one() + t * t).sqrt().recip();
+    let s = t * c;
+
+    let (c, s) = (T::from_real(c), T::from_real(s));
+    let g = [[c, s], [-s * phase.conj(), c * phase.conj()]];
+
+    for row in a.iter_mut().chain(v.iter_mut()) {
+        let (x, y) = (row[p], row[q]);
+
+        row[p] = x * g[0][0] + y * g[1][0];
+        row[q] = x * g[0][1] + y * g[1][1];
+    }
+
+    let (row_p, row_q) = (a[p], a[q]);
+
+    for k in 0..N {
+        a[p][k] = g[0][0].conj() * row_p[k] + g[1][0].conj() * row_q[k];
+        a[q][k] = g[0][1].conj() * row_p[k] + g[1][1].conj() * row_q[k];
+    }
+
+    a[p][q] = T::zero();
+    a[q][p] = T::zero();
+}
+
+fn pivot_row<T: Scalar, const N: usize>(rows: &[[T; N]; N], col: usize) -> usize {
+    (col..N)
+        .max_by(|&a, &b| {
+            rows[a][col]
+                .norm_sqr()
+                .partial_cmp(&rows[b][col].norm_sqr())
+                .unwrap()
+        })
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use num_complex::Complex64;
+    use num_rational::Ratio;
+
+    use crate::gate::{rx, HADAMARD, PAULI_X, PAULI_Y, T};
+
+    use super::*;
+
+    fn ratios<const N: usize>(rows: [[i64; N]; N]) -> Matrix<Ratio<i64>, N, N> {
+        Tensor(rows.map(|row| Tensor(row.map(Ratio::from_integer))))
+    }
+
+    #[test]
+    fn test_identity_trace() {
+        let i = Matrix::<f64, 3, 3>::identity();
+
+        assert_eq!(
+            i,
+            Tensor([
+                Tensor([1.0, 0.0, 0.0]),
+                Tensor([0.0, 1.0, 0.0]),
+                Tensor([0.0, 0.0, 1.0]),
+            ])
+        );
+        assert_eq!(i.trace(), 3.0);
+        assert_eq!(PAULI_Y.trace(), Complex64::default());
+    }
+
+    #[test]
+    fn test_determinant() {
+        assert_eq!(
+            ratios([[2, 0, 1], [1, 3, 2], [1, 1, 2]]).determinant(),
+            Ratio::from_integer(6)
+        );
+        assert_eq!(
+            ratios([[0, 1], [1, 0]]).determinant(),
+            Ratio::from_integer(-1)
+        );
+        assert_eq!(
+            ratios([[1, 2], [2, 4]]).determinant(),
+            Ratio::from_integer(0)
+        );
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = ratios([[2, 0, 1], [1, 3, 2], [1, 1, 2]]);
+
+        assert_eq!(a.clone() * a.inverse().unwrap(), Matrix::identity());
+        assert_eq!(ratios([[1, 2], [2, 4]]).inverse(), Err(SingularMatrixError));
+        assert_eq!(
+            Tensor([Tensor([1.0, 2.0]), Tensor([0.5, 1.0])]).inverse(),
+            Err(SingularMatrixError)
+        );
+    }
+
+    #[test]
+    fn test_unitarity() {
+        for u in [HADAMARD, PAULI_X, PAULI_Y, T] {
+            let deviation = u.T() * u.clone() - Matrix::<Complex64, 2, 2>::identity();
+
+            assert!(deviation.frobenius_norm() < 1e-12);
+            assert!((u.operator_norm() - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_eigen_pauli() {
+        for p in [PAULI_X, PAULI_Y] {
+            let (values, vectors) = p.eigen_hermitian();
+
+            assert!((values.as_array()[0] + 1.0).abs() < 1e-12);
+            assert!((values.as_array()[1] - 1.0).abs() < 1e-12);
+
+            for (value, vector) in values.0.into_iter().zip(vectors.0) {
+                let residual = p.clone() * vector.clone() - vector * Complex64::new(value, 0.0);
+
+                assert!(residual.0.iter().all(|n| n.norm() < 1e-12));
+            }
+        }
+    }
+
+    #[test]
+    fn test_eigen_hermitian_n3() {
+        let c = Complex64::new;
+        let a: Matrix<Complex64, 3, 3> = Tensor([
+            Tensor([c(2.0, 0.0), c(1.0, -1.0), c(0.0, 0.5)]),
+            Tensor([c(1.0, 1.0), c(-1.0, 0.0), c(0.3, 0.0)]),
+            Tensor([c(0.0, -0.5), c(0.3, 0.0), c(0.5, 0.0)]),
+        ]);
+
+        let (values, vectors) = a.eigen_hermitian();
+
+        assert!(values.0.windows(2).all(|w| w[0] <= w[1]));
+        assert!((values.0.iter().sum::<f64>() - a.trace().re).abs() < 1e-12);
+
+        let unitarity = vectors.clone() * vectors.T() - Matrix::<Complex64, 3, 3>::identity();
+        assert!(unitarity.frobenius_norm() < 1e-12);
+
+        for (value, vector) in values.0.into_iter().zip(vectors.0) {
+            let residual = a.clone() * vector.clone() - vector * Complex64::new(value, 0.0);
+
+            assert!(residual.0.iter().all(|n| n.norm() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_eigen_hermitian_part() {
+        let c = Complex64::new;
+        let a: Matrix<Complex64, 2, 2> = Tensor([
+            Tensor([c(1.0, 0.0), c(2.0, 0.0)]),
+            Tensor([c(0.0, 0.0), c(-1.0, 0.0)]),
+        ]);
+
+        let (values, _) = a.eigen_hermitian();
+
+        assert!((values.as_array()[0] + 2f64.sqrt()).abs() < 1e-12);
+        assert!((values.as_array()[1] - 2f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_expm() {
+        let zero = Matrix::<f64, 2, 2>::default();
+        assert_eq!(zero.expm(), Matrix::identity());
+
+        let d: Matrix<f64, 2, 2> = Tensor([Tensor([1.0, 0.0]), Tensor([0.0, -2.0])]);
+        let e = d.expm();
+        assert!((e.as_array()[0].as_array()[0] - 1f64.exp()).abs() < 1e-12);
+        assert!((e.as_array()[1].as_array()[1] - (-2f64).exp()).abs() < 1e-12);
+
+        // e^(-iθX/2) = Rx(θ), including a large angle that needs squaring
+        for theta in [0.3, 25.0] {
+            let generator = PAULI_X * Complex64::new(0.0, -theta / 2.0);
+
+            assert!((generator.expm() - rx(theta)).frobenius_norm() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_svd() {
+        let c = Complex64::new;
+        let a: Matrix<Complex64, 2, 3> = Tensor([
+            Tensor([c(1.0, 0.0), c(0.0, 2.0), c(0.0, 0.0)]),
+            Tensor([c(0.0, 0.0), c(1.0, 1.0), c(-1.0, 0.0)]),
+        ]);
+
+        let (u, s, v) = a.svd();
+
+        assert!(s.0[0] >= s.0[1]);
+        assert!(
+            (u.clone() * u.T() - Matrix::<Complex64, 2, 2>::identity()).frobenius_norm() < 1e-12
+        );
+        assert!(
+            (v.clone() * v.T() - Matrix::<Complex64, 3, 3>::identity()).frobenius_norm() < 1e-12
+        );
+
+        let rebuilt: Matrix<Complex64, 2, 3> = (0..2)
+            .map(|k| {
+                Tensor(
+                    u.0[k]
+                        .0
+                        .map(|x| Tensor(v.0[k].0.map(|y| x * y.conj() * s.0[k]))),
+                )
+            })
+            .sum();
+
+        assert!((rebuilt - a.clone()).frobenius_norm() < 1e-12);
+        assert!((s.0[0] - a.operator_norm()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_svd_rank_deficient() {
+        let a: Matrix<f64, 3, 2> =
+            Tensor([Tensor([1.0, 2.0]), Tensor([2.0, 4.0]), Tensor([0.0, 0.0])]);
+
+        let (_, s, v) = a.svd();
+
+        assert!((s.0[0] - 5.0).abs() < 1e-12);
+        assert!(s.0[1] < 1e-12 && s.0[2] < 1e-12);
+        assert!((v.clone() * v.T() - Matrix::<f64, 2, 2>::identity()).frobenius_norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_norms() {
+        let a: Matrix<f64, 2, 3> = Tensor([Tensor([3.0, 0.0, 0.0]), Tensor([0.0, 0.0, 4.0])]);
+
+        assert!((a.frobenius_norm() - 5.0).abs() < 1e-12);
+        assert!((a.operator_norm() - 4.0).abs() < 1e-12);
+        assert!((PAULI_X.frobenius_norm() - 2f64.sqrt()).abs() < 1e-12);
+    }
+}
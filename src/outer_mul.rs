@@ -1,4 +1,7 @@
-use crate::tensor::Tensor;
+use crate::{
+    scalar::{for_each_scalar, Scalar},
+    tensor::Tensor,
+};
 
 pub trait OuterMul<Rhs = Self> {
     type Output;
@@ -6,30 +9,41 @@ pub trait OuterMul<Rhs = Self> {
     fn outer_mul(self, rhs: Rhs) -> Self::Output;
 }
 
-impl OuterMul for f64 {
-    type Output = f64;
+impl<S> OuterMul for S
+where
+    S: Scalar,
+{
+    type Output = S;
 
     fn outer_mul(self, rhs: Self) -> Self::Output {
         self * rhs
     }
 }
 
-impl OuterMul for &f64 {
-    type Output = f64;
+macro_rules! impl_outer_mul_ref {
+    ($($s:ty),*) => {$(
+        impl OuterMul for &$s {
+            type Output = $s;
 
-    fn outer_mul(self, rhs: Self) -> Self::Output {
-        self * rhs
-    }
+            fn outer_mul(self, rhs: Self) -> Self::Output {
+                *self * *rhs
+            }
+        }
+    )*};
 }
 
-impl<T, const N: usize, const O: usize> OuterMul<Tensor<T, N, O>> for f64
+for_each_scalar!(impl_outer_mul_ref);
+
+impl<S, T, const N: usize, const O: usize> OuterMul<Tensor<T, N, O>> for S
 where
-    f64: OuterMul<T, Output = T>,
+    S: Scalar,
+    T: OuterMul<S, Output = T>,
 {
-    type Output = Tensor<<f64 as OuterMul<T>>::Output, N, O>;
+    type Output = Tensor<T, N, O>;
 
+    // Scaling commutes, so recurse on the smaller operand
     fn outer_mul(self, rhs: Tensor<T, N, O>) -> Self::Output {
-        Tensor(rhs.0.map(|v| self.outer_mul(v)))
+        Tensor(rhs.0.map(|v| v.outer_mul(self)))
     }
 }
 
@@ -47,19 +61,23 @@ where
     }
 }
 
-impl<T, const N: usize, const O: usize> OuterMul<f64> for Tensor<T, N, O>
+impl<S, T, const N: usize, const O: usize> OuterMul<S> for Tensor<T, N, O>
 where
-    T: OuterMul<f64, Output = T>,
+    S: Scalar,
+    T: OuterMul<S, Output = T>,
 {
-    type Output = Tensor<<T as OuterMul<f64>>::Output, N, O>;
+    type Output = Tensor<T, N, O>;
 
-    fn outer_mul(self, rhs: f64) -> Self::Output {
+    fn outer_mul(self, rhs: S) -> Self::Output {
         Tensor(self.0.map(|v| v.outer_mul(rhs)))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use num_complex::Complex64;
+    use num_rational::Ratio;
+
     use super::*;
 
     #[test]
@@ -67,6 +85,41 @@ mod tests {
         assert_eq!(2.0.outer_mul(3.0), 6.0);
     }
 
+    #[test]
+    fn test_complex_complex() {
+        assert_eq!(
+            Complex64::new(1.0, 2.0).outer_mul(Complex64::new(3.0, -1.0)),
+            Complex64::new(5.0, 5.0)
+        );
+    }
+
+    #[test]
+    fn test_ref_f64_ref_f64() {
+        assert_eq!((&2.0).outer_mul(&3.0), 6.0);
+    }
+
+    #[test]
+    fn test_ratio_tensor_n2_r1_tensor_n2_r1() {
+        let t1: Tensor<Ratio<i64>, 2, 1> = Tensor([Ratio::new(1, 2), Ratio::new(1, 3)]);
+
+        let t2: Tensor<Ratio<i64>, 2, 1> = Tensor([Ratio::new(2, 1), Ratio::new(3, 4)]);
+
+        let result = Tensor([
+            Tensor([Ratio::new(1, 1), Ratio::new(3, 8)]),
+            Tensor([Ratio::new(2, 3), Ratio::new(1, 4)]),
+        ]);
+
+        assert_eq!(t1.outer_mul(t2), result);
+    }
+
+    #[test]
+    fn test_f32_tensor_n2_r1() {
+        assert_eq!(
+            2.0f32.outer_mul(Tensor::<_, 2, 1>([1.0f32, 2.0])),
+            Tensor([2.0f32, 4.0])
+        );
+    }
+
     #[test]
     fn test_f64_tensor_n1_r1() {
         assert_eq!(2.0.outer_mul(Tensor::<_, 1, 1>([1.0])), Tensor([2.0]));
@@ -1,54 +1,198 @@
-use rand::{thread_rng, Rng};
+use num_complex::Complex64;
+use rand::Rng;
 
-use crate::{basis::Basis, tensor::Tensor, transposable::Transposable};
+use crate::{
+    basis::Basis,
+    evolution::{self, trotter_propagator, ZeroStepsError},
+    gate::Gate,
+    tensor::Tensor,
+};
 
 #[derive(Debug)]
 pub struct Qubit {
-    state: Tensor<f64, 2, 1>,
+    state: Tensor<Complex64, 2, 1>,
 }
 
 impl Qubit {
-    pub fn new(a: f64, b: f64) -> Self {
+    pub fn new(a: Complex64, b: Complex64) -> Self {
         Qubit {
             state: Tensor([a, b]),
         }
     }
 
-    pub fn random() -> Self {
-        let mut rng = thread_rng();
-
-        let a: f64 = rng.gen_range(-1.0..=1.0);
-        let b: f64 = rng.gen_range(-1.0..=1.0);
-        let c = (a * a + b * b).sqrt();
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let a = Complex64::new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0));
+        let b = Complex64::new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0));
+        let c = (a.norm_sqr() + b.norm_sqr()).sqrt();
 
         Qubit {
             state: Tensor([a / c, b / c]),
         }
     }
 
-    pub fn get_state(&self) -> &Tensor<f64, 2, 1> {
+    /// Spin up along the Bloch axis at polar angle `theta` and azimuth `phi`,
+    /// `cos(θ/2)|0⟩ + e^(iφ) sin(θ/2)|1⟩`.
+    pub fn from_bloch(theta: f64, phi: f64) -> Self {
+        let (sin, cos) = (theta / 2.0).sin_cos();
+
+        Qubit::new(cos.into(), Complex64::from_polar(sin, phi))
+    }
+
+    /// Pure state pointing along `vector`, which need not be unit length.
+    pub fn from_bloch_vector(vector: Tensor<f64, 3, 1>) -> Self {
+        let [x, y, z] = vector.0;
+
+        Self::from_bloch(x.hypot(y).atan2(z), y.atan2(x))
+    }
+
+    pub fn get_state(&self) -> &Tensor<Complex64, 2, 1> {
         &self.state
     }
 
-    pub fn get_probability_amplitudes(&self, basis: Basis) -> Tensor<f64, 2, 1> {
-        basis.as_tensor().T() * self.state.clone()
+    pub fn apply(&mut self, gate: &Gate<2>) {
+        self.state = gate.clone() * self.state.clone();
+    }
+
+    /// Evolves for time `t` under `hamiltonian`, with `ħ = 1`.
+    pub fn evolve(&mut self, hamiltonian: &Gate<2>, t: f64) {
+        self.state = evolution::evolve(self.state.clone(), hamiltonian, t);
+    }
+
+    /// Evolves for time `t` under the sum of `terms`, split into `steps` Trotter steps.
+    /// Fails, leaving the state as it was, if `steps` is 0.
+    pub fn evolve_trotter(
+        &mut self,
+        terms: &[Gate<2>],
+        t: f64,
+        steps: u32,
+    ) -> Result<(), ZeroStepsError> {
+        self.apply(&trotter_propagator(terms, t, steps)?);
+
+        Ok(())
+    }
+
+    /// `(x, y, z)` expectations of the Pauli operators, a unit vector.
+    pub fn get_bloch_vector(&self) -> Tensor<f64, 3, 1> {
+        let [a, b] = self.state.0;
+        let coherence = 2.0 * a.conj() * b;
+
+        Tensor([coherence.re, coherence.im, a.norm_sqr() - b.norm_sqr()])
+    }
+
+    /// Polar angle `theta` in `[0, π]` and azimuth `phi` in `(-π, π]`. The global
+    /// phase is dropped.
+    pub fn get_bloch_angles(&self) -> (f64, f64) {
+        let [x, y, z] = self.get_bloch_vector().0;
+
+        (z.clamp(-1.0, 1.0).acos(), y.atan2(x))
+    }
+
+    pub fn get_probability_amplitudes(&self, basis: Basis) -> Tensor<Complex64, 2, 1> {
+        basis.to_computational() * self.state.clone()
     }
 
     pub fn get_probabilities(&self, basis: Basis) -> Tensor<f64, 2, 1> {
-        self.get_probability_amplitudes(basis).map(|n| n.powi(2))
+        self.get_probability_amplitudes(basis).map(|n| n.norm_sqr())
     }
 
-    pub fn measure(&mut self, basis: Basis) -> bool {
+    pub fn measure<R: Rng + ?Sized>(&mut self, basis: Basis, rng: &mut R) -> bool {
         let [off_state, on_state] = basis.as_tensor().as_array().clone();
 
         let &[off_p, on_p] = self.get_probabilities(basis).as_array();
 
         debug_assert!((1.0 - (off_p + on_p)).abs() < 0.0001);
 
-        let is_on = thread_rng().gen_bool(on_p);
+        let is_on = rng.gen_bool(on_p.clamp(0.0, 1.0));
 
         self.state = if is_on { on_state } else { off_state };
 
         is_on
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{basis::DEG_45, gate::PAULI_X};
+
+    use super::*;
+
+    #[test]
+    fn test_circular_probabilities() {
+        let i = Complex64::i() * FRAC_1_SQRT_2;
+        let r = Complex64::new(FRAC_1_SQRT_2, 0.0);
+
+        let qb = Qubit::new(r, i);
+        let circular = Basis::new(Tensor([r, -i]), Tensor([r, i])).unwrap();
+
+        let &[off_p, on_p] = qb.get_probabilities(circular).as_array();
+
+        assert!(off_p.abs() < 1e-12);
+        assert!((on_p - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_seeded_runs_are_reproducible() {
+        let run = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+
+            (0..100)
+                .map(|_| Qubit::random(&mut rng).measure(DEG_45, &mut rng))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(run(42), run(42));
+        assert_ne!(run(42), run(43));
+    }
+
+    #[test]
+    fn test_bloch_round_trip() {
+        let (theta, phi) = (2.0f64, -1.0f64);
+        let qb = Qubit::from_bloch(theta, phi);
+
+        let (t, p) = qb.get_bloch_angles();
+        assert!((t - theta).abs() < 1e-12 && (p - phi).abs() < 1e-12);
+
+        let [x, y, z] = qb.get_bloch_vector().0;
+        assert!((x - theta.sin() * phi.cos()).abs() < 1e-12);
+        assert!((y - theta.sin() * phi.sin()).abs() < 1e-12);
+        assert!((z - theta.cos()).abs() < 1e-12);
+
+        let (t, p) =
+            Qubit::from_bloch_vector(Tensor([x * 3.0, y * 3.0, z * 3.0])).get_bloch_angles();
+        assert!((t - theta).abs() < 1e-12 && (p - phi).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_bloch_axes() {
+        let r = Complex64::new(FRAC_1_SQRT_2, 0.0);
+
+        assert_eq!(
+            Qubit::new(r, Complex64::i() * r)
+                .get_bloch_vector()
+                .map(f64::round),
+            Tensor([0.0, 1.0, 0.0])
+        );
+        assert_eq!(
+            Qubit::new(Complex64::default(), Complex64::i()).get_bloch_vector(),
+            Tensor([0.0, 0.0, -1.0])
+        );
+    }
+
+    #[test]
+    fn test_measure_observable() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let r = Complex64::new(FRAC_1_SQRT_2, 0.0);
+
+        let mut plus = Qubit::new(r, r);
+        let mut minus = Qubit::new(r, -r);
+
+        for _ in 0..20 {
+            assert!(plus.measure(Basis::from_observable(&PAULI_X), &mut rng));
+            assert!(!minus.measure(Basis::from_observable(&PAULI_X), &mut rng));
+        }
+    }
+}
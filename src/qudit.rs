@@ -0,0 +1,159 @@
+use num_complex::Complex64;
+use rand::Rng;
+
+use crate::{basis::Basis, gate::Gate, qubit::Qubit, tensor::Tensor};
+
+/// `D`-level system, e.g. a qutrit or a spin-1 particle.
+#[derive(Clone, Debug)]
+pub struct Qudit<const D: usize> {
+    state: Tensor<Complex64, D, 1>,
+}
+
+impl<const D: usize> Qudit<D> {
+    pub fn new(state: Tensor<Complex64, D, 1>) -> Self {
+        Qudit { state }
+    }
+
+    /// `|k⟩` of the computational basis.
+    pub fn basis_state(k: usize) -> Self {
+        let mut state = Tensor::default();
+        state.0[k] = Complex64::new(1.0, 0.0);
+
+        Qudit { state }
+    }
+
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let state =
+            [(); D].map(|_| Complex64::new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0)));
+        let c = state.iter().map(|n| n.norm_sqr()).sum::<f64>().sqrt();
+
+        Qudit {
+            state: Tensor(state.map(|n| n / c)),
+        }
+    }
+
+    pub fn get_state(&self) -> &Tensor<Complex64, D, 1> {
+        &self.state
+    }
+
+    pub fn apply(&mut self, gate: &Gate<D>) {
+        self.state = gate.clone() * self.state.clone();
+    }
+
+    pub fn get_probability_amplitudes(&self, basis: &Basis<D>) -> Tensor<Complex64, D, 1> {
+        basis.to_computational() * self.state.clone()
+    }
+
+    pub fn get_probabilities(&self, basis: &Basis<D>) -> Tensor<f64, D, 1> {
+        self.get_probability_amplitudes(basis).map(|n| n.norm_sqr())
+    }
+
+    /// Returns the index of the observed basis vector.
+    pub fn measure<R: Rng + ?Sized>(&mut self, basis: &Basis<D>, rng: &mut R) -> usize {
+        let probabilities = self.get_probabilities(basis);
+
+        debug_assert!((1.0 - probabilities.as_array().iter().sum::<f64>()).abs() < 0.0001);
+
+        let mut r = rng.gen::<f64>();
+
+        let outcome = probabilities
+            .as_array()
+            .iter()
+            .position(|&p| {
+                r -= p;
+
+                r < 0.0
+            })
+            .unwrap_or_else(|| {
+                // Rounding left `r` just above zero; fall back to the last possible outcome.
+                probabilities
+                    .as_array()
+                    .iter()
+                    .rposition(|&p| p > 0.0)
+                    .unwrap()
+            });
+
+        self.state = basis.as_tensor().as_array()[outcome].clone();
+
+        outcome
+    }
+}
+
+impl From<&Qubit> for Qudit<2> {
+    fn from(qubit: &Qubit) -> Self {
+        Qudit::new(qubit.get_state().clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::gate::{clock, shift, spin_x, spin_z};
+
+    use super::*;
+
+    #[test]
+    fn test_shift_cycles() {
+        let mut qt = Qudit::<3>::basis_state(0);
+
+        for k in 1..=3 {
+            qt.apply(&shift());
+
+            assert_eq!(qt.get_probabilities(&Basis::computational()).0[k % 3], 1.0);
+        }
+    }
+
+    #[test]
+    fn test_fourier_is_shift_eigenbasis() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let fourier = Basis::<3>::fourier();
+
+        for k in 0..3 {
+            let mut qt = Qudit::new(fourier.as_tensor().as_array()[k].clone());
+
+            qt.apply(&shift());
+
+            assert_eq!(qt.measure(&fourier, &mut rng), k);
+        }
+    }
+
+    #[test]
+    fn test_fourier_maps_clock_to_shift() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for k in 0..3 {
+            let mut qt = Qudit::<3>::new(Basis::fourier().as_tensor().as_array()[k].clone());
+
+            qt.apply(&clock());
+
+            assert_eq!(qt.measure(&Basis::fourier(), &mut rng), (k + 1) % 3);
+        }
+    }
+
+    #[test]
+    fn test_spin_1_measurement() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let sz = Basis::from_observable(&spin_z::<3>());
+        let sx = Basis::from_observable(&spin_x::<3>());
+
+        // m = +1 along z has outcomes -1, 0, +1 along x with probability 1/4, 1/2, 1/4
+        let qt = Qudit::<3>::basis_state(0);
+        let probabilities = qt.get_probabilities(&sx);
+
+        for (p, expected) in probabilities.as_array().iter().zip([0.25, 0.5, 0.25]) {
+            assert!((p - expected).abs() < 1e-9);
+        }
+
+        let counts = (0..4000).fold([0; 3], |mut counts, _| {
+            counts[qt.clone().measure(&sx, &mut rng)] += 1;
+
+            counts
+        });
+
+        assert!((counts[1] as f64 / 4000.0 - 0.5).abs() < 0.05);
+
+        // Ascending eigenvalues put m = +1 last
+        assert_eq!(Qudit::<3>::basis_state(0).measure(&sz, &mut rng), 2);
+    }
+}
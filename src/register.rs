@@ -0,0 +1,288 @@
+use std::array;
+
+use num_complex::Complex64;
+use rand::Rng;
+
+use crate::{
+    basis::Basis, density_matrix::DensityMatrix, gate::Gate, qubit::Qubit, tensor::Tensor,
+    transposable::Transposable, util::subsystem_offsets,
+};
+
+/// State vector of `N` qubits, `2^N` amplitudes. Qubit 0 is the most significant
+/// bit of the amplitude index, matching the order of `TensorMul`.
+#[derive(Clone, Debug)]
+pub struct Register<const N: usize>
+where
+    [(); 1 << N]:,
+{
+    state: Tensor<Complex64, { 1 << N }, 1>,
+}
+
+impl<const N: usize> Register<N>
+where
+    [(); 1 << N]:,
+{
+    /// All qubits in `|0⟩`.
+    pub fn new() -> Self {
+        let mut state = Tensor::default();
+        state.0[0] = Complex64::new(1.0, 0.0);
+
+        Register { state }
+    }
+
+    pub fn from_state(state: Tensor<Complex64, { 1 << N }, 1>) -> Self {
+        Register { state }
+    }
+
+    /// Product state `|q_0⟩ ⊗ |q_1⟩ ⊗ ... ⊗ |q_{N-1}⟩`.
+    pub fn from_qubits(qubits: [Qubit; N]) -> Self {
+        let mut idx = 0;
+
+        let state = [(); 1 << N].map(|_| {
+            let amplitude = qubits
+                .iter()
+                .enumerate()
+                .map(|(q, qb)| qb.get_state().as_array()[(idx >> (N - 1 - q)) & 1])
+                .product();
+
+            idx += 1;
+
+            amplitude
+        });
+
+        Register {
+            state: Tensor(state),
+        }
+    }
+
+    pub fn get_state(&self) -> &Tensor<Complex64, { 1 << N }, 1> {
+        &self.state
+    }
+
+    pub fn get_probabilities(&self) -> Tensor<f64, { 1 << N }, 1> {
+        self.state.clone().map(|n| n.norm_sqr())
+    }
+
+    /// Applies a `K`-qubit gate to `targets`. `targets[0]` is the most significant
+    /// bit of the gate's index.
+    pub fn apply<const K: usize>(&mut self, gate: &Gate<{ 1 << K }>, targets: [usize; K])
+    where
+        [(); 1 << K]:,
+    {
+        let offsets = Self::offsets(targets);
+        let target_mask = offsets[(1 << K) - 1];
+
+        let mut state = self.state.clone();
+
+        for base in (0..1 << N).filter(|base| base & target_mask == 0) {
+            let amplitudes = gate.clone() * Tensor(offsets.map(|o| self.state.0[base | o]));
+
+            for (amplitude, offset) in amplitudes.0.into_iter().zip(offsets) {
+                state.0[base | offset] = amplitude;
+            }
+        }
+
+        self.state = state;
+    }
+
+    /// Entanglement entropy in bits between `subsystem` and the rest of the
+    /// register, the von Neumann entropy of either reduced state.
+    pub fn get_entanglement_entropy<const K: usize>(&self, subsystem: [usize; K]) -> f64
+    where
+        [(); 1 << K]:,
+    {
+        DensityMatrix::from_pure(self.state.clone())
+            .partial_trace(subsystem)
+            .entropy()
+    }
+
+    /// Joint outcome probabilities of `targets` in the computational basis,
+    /// summed over every other qubit.
+    pub fn get_marginal_probabilities<const K: usize>(
+        &self,
+        targets: [usize; K],
+    ) -> Tensor<f64, { 1 << K }, 1>
+    where
+        [(); 1 << K]:,
+    {
+        let offsets = Self::offsets(targets);
+        let target_mask = offsets[(1 << K) - 1];
+
+        let mut probabilities = Tensor::<f64, { 1 << K }, 1>::default();
+
+        for (idx, amplitude) in self.state.as_array().iter().enumerate() {
+            let outcome = offsets
+                .iter()
+                .position(|&o| o == idx & target_mask)
+                .unwrap();
+
+            probabilities.0[outcome] += amplitude.norm_sqr();
+        }
+
+        probabilities
+    }
+
+    /// Measures `qubit` in `basis`, collapsing the remaining amplitudes onto the
+    /// observed outcome. Returns whether the `on` basis vector was observed.
+    pub fn measure<R: Rng + ?Sized>(&mut self, qubit: usize, basis: Basis, rng: &mut R) -> bool {
+        let to_computational = basis.to_computational();
+        let from_computational = to_computational.T();
+
+        self.apply::<1>(&to_computational, [qubit]);
+
+        let &[off_p, on_p] = self.get_marginal_probabilities::<1>([qubit]).as_array();
+
+        debug_assert!((1.0 - (off_p + on_p)).abs() < 0.0001);
+
+        let is_on = rng.gen_bool(on_p.clamp(0.0, 1.0));
+
+        let mask = 1 << (N - 1 - qubit);
+        let norm = if is_on { on_p } else { off_p }.sqrt();
+
+        for (idx, amplitude) in self.state.0.iter_mut().enumerate() {
+            *amplitude = if (idx & mask != 0) == is_on {
+                *amplitude / norm
+            } else {
+                Complex64::default()
+            };
+        }
+
+        self.apply::<1>(&from_computational, [qubit]);
+
+        is_on
+    }
+
+    /// Index offset of every joint state of `targets`, in the gate's ordering.
+    fn offsets<const K: usize>(targets: [usize; K]) -> [usize; 1 << K] {
+        let offsets = subsystem_offsets(&targets, N);
+
+        array::from_fn(|sub| offsets[sub])
+    }
+}
+
+impl<const N: usize> Default for Register<N>
+where
+    [(); 1 << N]:,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{
+        basis::{DEG_0, DEG_45},
+        gate::{CNOT, HADAMARD},
+    };
+
+    use super::*;
+
+    fn c(re: f64) -> Complex64 {
+        Complex64::new(re, 0.0)
+    }
+
+    fn assert_close<const N: usize>(a: &Tensor<f64, N, 1>, b: &Tensor<f64, N, 1>) {
+        for (x, y) in a.as_array().iter().zip(b.as_array()) {
+            assert!((x - y).abs() < 1e-12, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_single_qubit_gate_target() {
+        let mut reg = Register::<2>::new();
+
+        reg.apply(&HADAMARD, [1]);
+
+        assert_close(&reg.get_probabilities(), &Tensor([0.5, 0.5, 0.0, 0.0]));
+    }
+
+    #[test]
+    fn test_bell_state() {
+        let mut reg = Register::<2>::new();
+
+        reg.apply(&HADAMARD, [0]);
+        reg.apply(&CNOT, [0, 1]);
+
+        assert_close(&reg.get_probabilities(), &Tensor([0.5, 0.0, 0.0, 0.5]));
+    }
+
+    #[test]
+    fn test_reversed_targets() {
+        let mut reg = Register::<3>::new();
+
+        reg.apply(&HADAMARD, [2]);
+        reg.apply(&CNOT, [2, 0]);
+
+        assert_close(
+            &reg.get_marginal_probabilities([0, 2]),
+            &Tensor([0.5, 0.0, 0.0, 0.5]),
+        );
+        assert_close(&reg.get_marginal_probabilities([1]), &Tensor([1.0, 0.0]));
+    }
+
+    #[test]
+    fn test_entanglement_entropy() {
+        let mut reg = Register::<3>::new();
+
+        reg.apply(&HADAMARD, [0]);
+        reg.apply(&CNOT, [0, 2]);
+        reg.apply(&HADAMARD, [1]);
+
+        assert!((reg.get_entanglement_entropy([0]) - 1.0).abs() < 1e-9);
+        assert!((reg.get_entanglement_entropy([0, 2])).abs() < 1e-9);
+        assert!((reg.get_entanglement_entropy([1])).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_from_qubits() {
+        let r = c(FRAC_1_SQRT_2);
+        let reg = Register::from_qubits([Qubit::new(c(0.0), c(1.0)), Qubit::new(r, r)]);
+
+        assert_close(&reg.get_probabilities(), &Tensor([0.0, 0.0, 0.5, 0.5]));
+    }
+
+    #[test]
+    fn test_measure_collapses_partner() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for _ in 0..20 {
+            let mut reg = Register::<2>::new();
+
+            reg.apply(&HADAMARD, [0]);
+            reg.apply(&CNOT, [0, 1]);
+
+            let a = reg.measure(0, DEG_0, &mut rng);
+
+            assert_close(
+                &reg.get_marginal_probabilities([1]),
+                &if a {
+                    Tensor([0.0, 1.0])
+                } else {
+                    Tensor([1.0, 0.0])
+                },
+            );
+            assert_eq!(reg.measure(1, DEG_0, &mut rng), a);
+        }
+    }
+
+    #[test]
+    fn test_measure_in_rotated_basis() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for _ in 0..20 {
+            let mut reg = Register::<2>::new();
+
+            reg.apply(&HADAMARD, [0]);
+            reg.apply(&CNOT, [0, 1]);
+
+            let a = reg.measure(0, DEG_45, &mut rng);
+
+            assert_eq!(reg.measure(1, DEG_45, &mut rng), a);
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use std::fmt;
+
+use num_complex::Complex64;
+use rand::Rng;
+
+use crate::{basis::Basis, tensor::Tensor};
+
+/// Histogram of outcomes over a number of shots. Outcome `k` is the `k`th basis
+/// vector, shown as a bitstring when `D` is a power of two.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Counts<const D: usize> {
+    counts: [u64; D],
+    shots: u64,
+}
+
+impl<const D: usize> Counts<D> {
+    /// Draws `shots` outcomes from `probabilities`, which are expected to sum to 1.
+    ///
+    /// Panics if the probabilities sum to 0.
+    pub fn from_probabilities<R: Rng + ?Sized>(
+        probabilities: &Tensor<f64, D, 1>,
+        shots: u64,
+        rng: &mut R,
+    ) -> Self {
+        let mut total = 0.0;
+        let cumulative = probabilities.as_array().map(|p| {
+            total += p;
+
+            total
+        });
+
+        assert!(total > 0.0, "probabilities sum to {}, not 1", total);
+        debug_assert!((1.0 - total).abs() < 0.0001);
+
+        let mut counts = [0; D];
+
+        for _ in 0..shots {
+            let r = rng.gen_range(0.0..total);
+
+            // Clamp in case rounding leaves `r` past the last boundary
+            counts[cumulative.partition_point(|&c| c <= r).min(D - 1)] += 1;
+        }
+
+        Counts { counts, shots }
+    }
+
+    pub fn as_array(&self) -> &[u64; D] {
+        &self.counts
+    }
+
+    pub fn get(&self, outcome: usize) -> u64 {
+        self.counts[outcome]
+    }
+
+    pub fn shots(&self) -> u64 {
+        self.shots
+    }
+
+    /// Fraction of shots that gave `outcome`.
+    ///
+    /// Panics if there were no shots, as do the estimates built on it.
+    pub fn frequency(&self, outcome: usize) -> f64 {
+        assert!(self.shots > 0, "no shots to estimate a frequency from");
+
+        self.counts[outcome] as f64 / self.shots as f64
+    }
+
+    /// Binomial standard error of `frequency(outcome)`.
+    pub fn standard_error(&self, outcome: usize) -> f64 {
+        let p = self.frequency(outcome);
+
+        (p * (1.0 - p) / self.shots as f64).sqrt()
+    }
+
+    /// Wilson score interval for the probability of `outcome`, with `z` standard
+    /// normal quantiles either side, e.g. `1.96` for 95%.
+    pub fn confidence_interval(&self, outcome: usize, z: f64) -> (f64, f64) {
+        let n = self.shots as f64;
+        let p = self.frequency(outcome);
+        let z2 = z * z;
+
+        let center = (p + z2 / (2.0 * n)) / (1.0 + z2 / n);
+        let half_width = z / (1.0 + z2 / n) * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt();
+
+        (
+            (center - half_width).max(0.0),
+            (center + half_width).min(1.0),
+        )
+    }
+
+    /// `outcome` as a bitstring with qubit 0 first, or in decimal if `D` is not a
+    /// power of two.
+    pub fn label(outcome: usize) -> String {
+        if D.is_power_of_two() {
+            format!("{:0width$b}", outcome, width = D.trailing_zeros() as usize)
+        } else {
+            outcome.to_string()
+        }
+    }
+}
+
+impl<const D: usize> fmt::Display for Counts<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (outcome, count) in self.counts.iter().enumerate() {
+            writeln!(f, "{}: {}", Self::label(outcome), count)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Measures `shots` copies of `state` in `basis`. The probabilities are computed
+/// once, so no state is cloned or collapsed per shot.
+pub fn sample<const D: usize, R: Rng + ?Sized>(
+    state: &Tensor<Complex64, D, 1>,
+    basis: &Basis<D>,
+    shots: u64,
+    rng: &mut R,
+) -> Counts<D> {
+    let probabilities = (basis.to_computational() * state.clone()).map(|n| n.norm_sqr());
+
+    Counts::from_probabilities(&probabilities, shots, rng)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{basis::DEG_0, entangled_pair::EntangledPair};
+
+    use super::*;
+
+    #[test]
+    fn test_bell_histogram() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let counts = sample(
+            EntangledPair::phi_plus().get_state(),
+            &Basis::computational(),
+            10_000,
+            &mut rng,
+        );
+
+        assert_eq!(counts.shots(), 10_000);
+        assert_eq!(counts.get(0b01) + counts.get(0b10), 0);
+        assert_eq!(counts.get(0b00) + counts.get(0b11), 10_000);
+
+        let (low, high) = counts.confidence_interval(0b00, 3.0);
+
+        assert!(low < 0.5 && 0.5 < high);
+        assert!((counts.frequency(0b00) - 0.5).abs() < 3.0 * counts.standard_error(0b00));
+    }
+
+    #[test]
+    fn test_deterministic_outcome() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let state = Tensor([Complex64::new(0.0, 0.0), Complex64::new(0.0, 1.0)]);
+
+        let counts = sample(&state, &DEG_0, 100, &mut rng);
+
+        assert_eq!(counts.as_array(), &[0, 100]);
+        assert!((counts.confidence_interval(1, 1.96).1 - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "probabilities sum to 0")]
+    fn test_zero_probabilities() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        Counts::from_probabilities(&Tensor([0.0, 0.0]), 10, &mut rng);
+    }
+
+    #[test]
+    #[should_panic(expected = "no shots")]
+    fn test_no_shots() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let counts = Counts::from_probabilities(&Tensor([0.5, 0.5]), 0, &mut rng);
+
+        assert_eq!(counts.as_array(), &[0, 0]);
+
+        counts.confidence_interval(0, 1.96);
+    }
+
+    #[test]
+    fn test_labels() {
+        assert_eq!(Counts::<4>::label(0b01), "01");
+        assert_eq!(Counts::<3>::label(2), "2");
+
+        let counts = Counts {
+            counts: [3, 0, 1, 0],
+            shots: 4,
+        };
+
+        assert_eq!(counts.to_string(), "00: 3\n01: 0\n10: 1\n11: 0\n");
+    }
+}
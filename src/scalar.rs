@@ -0,0 +1,138 @@
+use std::{fmt::Debug, iter::Sum, ops::Neg};
+
+use num_complex::Complex;
+use num_rational::Ratio;
+use num_traits::Num;
+
+/// Leaf element of a `Tensor`: real or complex floats, or exact rationals.
+pub trait Scalar: Num + Copy + Neg<Output = Self> + Sum + Default + Debug + 'static {
+    /// The field of magnitudes, e.g. `f64` for `Complex<f64>`.
+    type Real: Scalar + PartialOrd;
+
+    fn conj(self) -> Self;
+
+    /// Squared modulus, `|z|²`.
+    fn norm_sqr(self) -> Self::Real;
+
+    fn from_real(re: Self::Real) -> Self;
+
+    fn re(self) -> Self::Real;
+
+    fn im(self) -> Self::Real;
+
+    /// Rounding error of one arithmetic operation; zero for exact types.
+    fn epsilon() -> Self::Real;
+}
+
+macro_rules! impl_real_scalar {
+    ($($t:ty => $eps:expr),*) => {$(
+        impl Scalar for $t {
+            type Real = $t;
+
+            fn conj(self) -> Self {
+                self
+            }
+
+            fn norm_sqr(self) -> Self::Real {
+                self * self
+            }
+
+            fn from_real(re: Self::Real) -> Self {
+                re
+            }
+
+            fn re(self) -> Self::Real {
+                self
+            }
+
+            fn im(self) -> Self::Real {
+                <$t as num_traits::Zero>::zero()
+            }
+
+            fn epsilon() -> Self::Real {
+                $eps
+            }
+        }
+    )*};
+}
+
+impl_real_scalar!(
+    f32 => f32::EPSILON,
+    f64 => f64::EPSILON,
+    Ratio<i32> => Ratio::new_raw(0, 1),
+    Ratio<i64> => Ratio::new_raw(0, 1)
+);
+
+macro_rules! impl_complex_scalar {
+    ($($t:ty),*) => {$(
+        impl Scalar for Complex<$t> {
+            type Real = $t;
+
+            fn conj(self) -> Self {
+                Complex::conj(&self)
+            }
+
+            fn norm_sqr(self) -> Self::Real {
+                Complex::norm_sqr(&self)
+            }
+
+            fn from_real(re: Self::Real) -> Self {
+                Complex::new(re, 0.0)
+            }
+
+            fn re(self) -> Self::Real {
+                self.re
+            }
+
+            fn im(self) -> Self::Real {
+                self.im
+            }
+
+            fn epsilon() -> Self::Real {
+                <$t>::EPSILON
+            }
+        }
+    )*};
+}
+
+impl_complex_scalar!(f32, f64);
+
+/// Invokes `$m!` with every concrete `Scalar` type, for impls the orphan rules
+/// won't let us write generically (e.g. `f64 * Tensor`).
+macro_rules! for_each_scalar {
+    ($m:ident) => {
+        $m!(
+            f32,
+            f64,
+            num_complex::Complex<f32>,
+            num_complex::Complex<f64>,
+            num_rational::Ratio<i32>,
+            num_rational::Ratio<i64>
+        );
+    };
+}
+
+pub(crate) use for_each_scalar;
+
+#[cfg(test)]
+mod tests {
+    use num_complex::Complex64;
+
+    use super::*;
+
+    #[test]
+    fn test_complex_conj_norm_sqr() {
+        let z = Complex64::new(3.0, -4.0);
+
+        assert_eq!(Scalar::conj(z), Complex64::new(3.0, 4.0));
+        assert_eq!(Scalar::norm_sqr(z), 25.0);
+    }
+
+    #[test]
+    fn test_ratio_norm_sqr() {
+        let q = Ratio::new(-2i64, 3);
+
+        assert_eq!(q.conj(), q);
+        assert_eq!(q.norm_sqr(), Ratio::new(4, 9));
+    }
+}
@@ -0,0 +1,142 @@
+use num_complex::Complex64;
+
+use crate::{
+    basis::TOLERANCE,
+    tensor::{Matrix, Tensor},
+};
+
+/// Schmidt decomposition `|ψ⟩ = Σ_k λ_k |a_k⟩ ⊗ |b_k⟩` of a state of an `N`-level
+/// system `A` and an `M`-level system `B`, with `A` the more significant index as
+/// in `TensorMul`.
+///
+/// Returns the `λ_k` in descending order, zero past `min(N, M)`, and the `|a_k⟩`
+/// and `|b_k⟩` as the rows of matrices laid out like `Basis`.
+pub fn schmidt_decomposition<const N: usize, const M: usize>(
+    state: &Tensor<Complex64, { N * M }, 1>,
+) -> (
+    Tensor<f64, N, 1>,
+    Matrix<Complex64, N, N>,
+    Matrix<Complex64, M, M>,
+) {
+    let mut psi = Matrix::<Complex64, N, M>::default();
+
+    for (idx, amplitude) in state.as_array().iter().enumerate() {
+        psi.0[idx / M].0[idx % M] = *amplitude;
+    }
+
+    // ψ_ij = Σ_k σ_k u_k[i] v_k[j]*, so |b_k⟩ = |v_k⟩*
+    let (u, s, v) = psi.svd();
+
+    (s, u, v.map(|row| row.map(|n| n.conj())))
+}
+
+/// Whether `state` factorises as `|a⟩ ⊗ |b⟩`, i.e. has a single Schmidt
+/// coefficient.
+pub fn is_product_state<const N: usize, const M: usize>(
+    state: &Tensor<Complex64, { N * M }, 1>,
+) -> bool {
+    let (coefficients, _, _) = schmidt_decomposition::<N, M>(state);
+
+    coefficients.0.iter().skip(1).all(|&l| l <= TOLERANCE)
+}
+
+/// Entanglement entropy `-Σ λ_k² log₂ λ_k²` in bits between `A` and `B`, from
+/// the Schmidt coefficients of `state`.
+pub fn entanglement_entropy<const N: usize, const M: usize>(
+    state: &Tensor<Complex64, { N * M }, 1>,
+) -> f64 {
+    let (coefficients, _, _) = schmidt_decomposition::<N, M>(state);
+
+    coefficients
+        .0
+        .iter()
+        .map(|l| l * l)
+        .filter(|&p| p > 0.0)
+        .map(|p| -p * p.log2())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    use crate::{basis::Basis, entangled_pair::EntangledPair, tensor_mul::TensorMul};
+
+    use super::*;
+
+    fn c(re: f64, im: f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    #[test]
+    fn test_bell_decomposition() {
+        let (coefficients, a, b) =
+            schmidt_decomposition::<2, 2>(EntangledPair::psi_minus().get_state());
+
+        for l in coefficients.0 {
+            assert!((l - FRAC_1_SQRT_2).abs() < 1e-12);
+        }
+
+        assert!(Basis::from_vectors(a.0).is_ok());
+        assert!(Basis::from_vectors(b.0).is_ok());
+        assert!(!is_product_state::<2, 2>(
+            EntangledPair::phi_plus().get_state()
+        ));
+    }
+
+    #[test]
+    fn test_entanglement_entropy() {
+        assert!(
+            (entanglement_entropy::<2, 2>(EntangledPair::phi_minus().get_state()) - 1.0).abs()
+                < 1e-12
+        );
+
+        // cos(θ)|00⟩ + sin(θ)|11⟩ has entropy h(cos²θ)
+        let (sin, cos) = 0.3f64.sin_cos();
+        let state = Tensor([c(cos, 0.0), c(0.0, 0.0), c(0.0, 0.0), c(sin, 0.0)]);
+        let (p, q) = (cos * cos, sin * sin);
+
+        assert!((entanglement_entropy::<2, 2>(&state) + p * p.log2() + q * q.log2()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_product_state() {
+        let a = Tensor([c(0.6, 0.0), c(0.0, 0.8)]);
+        let b = Tensor([c(0.0, 1.0), c(1.0, 0.0), c(1.0, 0.0)]) * c(3f64.sqrt().recip(), 0.0);
+        let state = a.clone().tensor_mul(b.clone());
+
+        assert!(is_product_state::<2, 3>(&state));
+
+        let (coefficients, a_k, b_k) = schmidt_decomposition::<2, 3>(&state);
+
+        assert!((coefficients.0[0] - 1.0).abs() < 1e-12);
+
+        // Factors agree up to a global phase
+        let overlap_a: Complex64 = a_k.0[0].clone().map(|n| n.conj()) * a;
+        let overlap_b: Complex64 = b_k.0[0].clone().map(|n| n.conj()) * b;
+
+        assert!((overlap_a.norm() - 1.0).abs() < 1e-12);
+        assert!((overlap_b.norm() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_reconstruction() {
+        let state = Tensor([c(0.1, 0.2), c(0.3, -0.1), c(0.0, 0.5), c(-0.4, 0.0)]);
+        let norm = state
+            .as_array()
+            .iter()
+            .map(|n| n.norm_sqr())
+            .sum::<f64>()
+            .sqrt();
+        let state = state * c(norm.recip(), 0.0);
+
+        let (coefficients, a, b) = schmidt_decomposition::<2, 2>(&state);
+
+        let rebuilt: Tensor<Complex64, 4, 1> = (0..2)
+            .map(|k| a.0[k].clone().tensor_mul(b.0[k].clone()) * c(coefficients.0[k], 0.0))
+            .sum();
+
+        assert!((rebuilt - state).0.iter().all(|n| n.norm() < 1e-12));
+        assert!((coefficients.0.iter().map(|l| l * l).sum::<f64>() - 1.0).abs() < 1e-12);
+    }
+}
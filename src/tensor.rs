@@ -1,8 +1,13 @@
 use std::{
     iter::Sum,
-    ops::{Add, Mul},
+    ops::{Add, Mul, Neg, Sub},
 };
 
+use crate::scalar::{for_each_scalar, Scalar};
+
+/// Rank-2 tensor with `N` rows of `M` columns, stored row-major.
+pub type Matrix<T, const N: usize, const M: usize> = Tensor<Tensor<T, M, 1>, N, 2>;
+
 #[derive(Clone, PartialEq, Debug)]
 pub struct Tensor<T, const N: usize, const O: usize>(pub [T; N]);
 impl<T, const N: usize, const O: usize> Tensor<T, N, O> {
@@ -41,6 +46,30 @@ where
     }
 }
 
+impl<T, U, const N: usize, const O: usize> Sub<Tensor<U, N, O>> for Tensor<T, N, O>
+where
+    T: Sub<U>,
+{
+    type Output = Tensor<<T as Sub<U>>::Output, N, O>;
+
+    fn sub(self, rhs: Tensor<U, N, O>) -> Self::Output {
+        let mut rhs_it = rhs.0.into_iter();
+
+        Tensor(self.0.map(|it| it - rhs_it.next().unwrap()))
+    }
+}
+
+impl<T, const N: usize, const O: usize> Neg for Tensor<T, N, O>
+where
+    T: Neg,
+{
+    type Output = Tensor<<T as Neg>::Output, N, O>;
+
+    fn neg(self) -> Self::Output {
+        Tensor(self.0.map(|it| -it))
+    }
+}
+
 impl<T, const N: usize, const O: usize> Sum for Tensor<T, N, O>
 where
     T: Add<T, Output = T> + Default,
@@ -50,45 +79,176 @@ where
     }
 }
 
-impl<T, U, const N: usize, const O1: usize, const O2: usize> Mul<Tensor<U, N, O2>>
-    for Tensor<T, N, O1>
+/// Dot product of two vectors.
+impl<T, U, const N: usize> Mul<Tensor<U, N, 1>> for Tensor<T, N, 1>
 where
-    T: Clone + Mul<U>,
+    T: Mul<U>,
     <T as Mul<U>>::Output: Sum,
 {
     type Output = <T as Mul<U>>::Output;
 
-    fn mul(self, rhs: Tensor<U, N, O2>) -> Self::Output {
-        self.0
-            .clone()
-            .into_iter()
-            .zip(rhs.0)
-            .map(|(a, b)| a * b)
-            .sum()
+    fn mul(self, rhs: Tensor<U, N, 1>) -> Self::Output {
+        self.0.into_iter().zip(rhs.0).map(|(a, b)| a * b).sum()
+    }
+}
+
+/// Row vector times matrix, `vA`.
+impl<T, U, const N: usize, const M: usize> Mul<Matrix<U, N, M>> for Tensor<T, N, 1>
+where
+    T: Clone + Mul<U>,
+    U: Clone,
+    <T as Mul<U>>::Output: Sum,
+{
+    type Output = Tensor<<T as Mul<U>>::Output, M, 1>;
+
+    fn mul(self, rhs: Matrix<U, N, M>) -> Self::Output {
+        let mut col_idx = 0;
+
+        Tensor([(); M].map(|_| {
+            let rv = self
+                .0
+                .iter()
+                .zip(rhs.as_array())
+                .map(|(v, row)| v.clone() * row.as_array()[col_idx].clone())
+                .sum();
+
+            col_idx += 1;
+
+            rv
+        }))
     }
 }
 
-impl<T, const N: usize, const O: usize> Mul<f64> for Tensor<T, N, O>
+/// Matrix times column vector, `Av`.
+impl<T, U, const N: usize, const M: usize> Mul<Tensor<U, M, 1>> for Matrix<T, N, M>
 where
-    T: Clone + Mul<f64>,
-    <T as Mul<f64>>::Output: Sum,
+    T: Mul<U>,
+    U: Clone,
+    <T as Mul<U>>::Output: Sum,
 {
-    type Output = Tensor<<T as Mul<f64>>::Output, N, O>;
+    type Output = Tensor<<T as Mul<U>>::Output, N, 1>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
-        Tensor(self.0.clone().map(|it| it * rhs))
+    fn mul(self, rhs: Tensor<U, M, 1>) -> Self::Output {
+        Tensor(self.0.map(|row| row * rhs.clone()))
     }
 }
 
-impl<T, const N: usize, const O: usize> Mul<Tensor<T, N, O>> for f64
+/// Matrix product, `AB`.
+impl<T, U, const N: usize, const M: usize, const P: usize> Mul<Matrix<U, M, P>> for Matrix<T, N, M>
 where
-    T: Clone,
-    f64: Mul<T>,
-    <f64 as Mul<T>>::Output: Sum,
+    T: Clone + Mul<U>,
+    U: Clone,
+    <T as Mul<U>>::Output: Sum,
 {
-    type Output = Tensor<<f64 as Mul<T>>::Output, N, O>;
+    type Output = Matrix<<T as Mul<U>>::Output, N, P>;
+
+    fn mul(self, rhs: Matrix<U, M, P>) -> Self::Output {
+        Tensor(self.0.map(|row| row * rhs.clone()))
+    }
+}
+
+impl<T, S, const N: usize, const O: usize> Mul<S> for Tensor<T, N, O>
+where
+    T: Mul<S>,
+    S: Scalar,
+{
+    type Output = Tensor<<T as Mul<S>>::Output, N, O>;
+
+    fn mul(self, rhs: S) -> Self::Output {
+        Tensor(self.0.map(|it| it * rhs))
+    }
+}
+
+macro_rules! impl_scalar_mul_tensor {
+    ($($s:ty),*) => {$(
+        impl<T, const N: usize, const O: usize> Mul<Tensor<T, N, O>> for $s
+        where
+            $s: Mul<T>,
+        {
+            type Output = Tensor<<$s as Mul<T>>::Output, N, O>;
+
+            fn mul(self, rhs: Tensor<T, N, O>) -> Self::Output {
+                Tensor(rhs.0.map(|it| self * it))
+            }
+        }
+    )*};
+}
+
+for_each_scalar!(impl_scalar_mul_tensor);
+
+#[cfg(test)]
+mod tests {
+    use num_complex::Complex32;
+    use num_rational::Ratio;
+
+    use super::*;
+
+    #[test]
+    fn test_tensor_n3_r1_dot() {
+        let t1: Tensor<f64, 3, 1> = Tensor([1.0, 2.0, 3.0]);
+        let t2: Tensor<f64, 3, 1> = Tensor([4.0, 5.0, 6.0]);
+
+        assert_eq!(t1 * t2, 32.0);
+    }
+
+    #[test]
+    fn test_tensor_n2_r1_matrix_n2_m3() {
+        let v: Tensor<f64, 2, 1> = Tensor([1.0, 2.0]);
+        let a: Matrix<f64, 2, 3> = Tensor([Tensor([1.0, 2.0, 3.0]), Tensor([4.0, 5.0, 6.0])]);
+
+        assert_eq!(v * a, Tensor([9.0, 12.0, 15.0]));
+    }
+
+    #[test]
+    fn test_matrix_n2_m3_tensor_n3_r1() {
+        let a: Matrix<f64, 2, 3> = Tensor([Tensor([1.0, 2.0, 3.0]), Tensor([4.0, 5.0, 6.0])]);
+        let v: Tensor<f64, 3, 1> = Tensor([1.0, 0.0, -1.0]);
+
+        assert_eq!(a * v, Tensor([-2.0, -2.0]));
+    }
+
+    #[test]
+    fn test_matrix_n2_m3_matrix_n3_m2() {
+        let a: Matrix<Ratio<i64>, 2, 3> = Tensor([
+            Tensor([1, 2, 3].map(Ratio::from_integer)),
+            Tensor([4, 5, 6].map(Ratio::from_integer)),
+        ]);
+        let b: Matrix<Ratio<i64>, 3, 2> = Tensor([
+            Tensor([1, 0].map(Ratio::from_integer)),
+            Tensor([0, 1].map(Ratio::from_integer)),
+            Tensor([1, 1].map(Ratio::from_integer)),
+        ]);
+
+        let result: Matrix<Ratio<i64>, 2, 2> = Tensor([
+            Tensor([4, 5].map(Ratio::from_integer)),
+            Tensor([10, 11].map(Ratio::from_integer)),
+        ]);
+
+        assert_eq!(a * b, result);
+    }
+
+    #[test]
+    fn test_ratio_scalar_mul() {
+        let t: Tensor<Ratio<i64>, 2, 1> = Tensor([Ratio::new(1, 2), Ratio::new(2, 3)]);
+
+        assert_eq!(
+            Ratio::new(3, 4) * t.clone(),
+            Tensor([Ratio::new(3, 8), Ratio::new(1, 2)])
+        );
+        assert_eq!(
+            t * Ratio::new(3, 1),
+            Tensor([Ratio::new(3, 2), Ratio::new(2, 1)])
+        );
+    }
+
+    #[test]
+    fn test_complex32_scalar_mul() {
+        let t: Tensor<Complex32, 2, 1> =
+            Tensor([Complex32::new(1.0, 0.0), Complex32::new(0.0, 1.0)]);
 
-    fn mul(self, rhs: Tensor<T, N, O>) -> Self::Output {
-        Tensor(rhs.0.clone().map(|it| self * it))
+        assert_eq!(
+            Complex32::i() * t,
+            Tensor([Complex32::new(0.0, 1.0), Complex32::new(-1.0, 0.0)])
+        );
     }
 }
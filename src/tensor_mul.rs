@@ -0,0 +1,65 @@
+use crate::{scalar::Scalar, tensor::Tensor, util::flatten_arrays};
+
+/// Kronecker product. Unlike `OuterMul`, the result keeps the rank of the operands
+/// and multiplies their dimensions, e.g. `(N) ⊗ (M) -> (N * M)`.
+pub trait TensorMul<Rhs = Self> {
+    type Output;
+
+    fn tensor_mul(self, rhs: Rhs) -> Self::Output;
+}
+
+impl<S> TensorMul for S
+where
+    S: Scalar,
+{
+    type Output = S;
+
+    fn tensor_mul(self, rhs: Self) -> Self::Output {
+        self * rhs
+    }
+}
+
+impl<T, U, const N: usize, const M: usize, const O: usize> TensorMul<Tensor<U, M, O>>
+    for Tensor<T, N, O>
+where
+    T: Clone + TensorMul<U>,
+    U: Clone,
+    [(); N * M]:,
+{
+    type Output = Tensor<<T as TensorMul<U>>::Output, { N * M }, O>;
+
+    fn tensor_mul(self, rhs: Tensor<U, M, O>) -> Self::Output {
+        Tensor(flatten_arrays(
+            self.0
+                .map(|a| rhs.0.clone().map(|b| a.clone().tensor_mul(b))),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tensor_n2_r1_tensor_n2_r1() {
+        let t1: Tensor<f64, 2, 1> = Tensor([1.0, 2.0]);
+        let t2: Tensor<f64, 2, 1> = Tensor([3.0, 4.0]);
+
+        assert_eq!(t1.tensor_mul(t2), Tensor([3.0, 4.0, 6.0, 8.0]));
+    }
+
+    #[test]
+    fn test_tensor_n2_r2_tensor_n2_r2() {
+        let t1: Tensor<Tensor<f64, 2, 1>, 2, 2> = Tensor([Tensor([0.0, 1.0]), Tensor([1.0, 0.0])]);
+        let t2: Tensor<Tensor<f64, 2, 1>, 2, 2> = Tensor([Tensor([1.0, 2.0]), Tensor([3.0, 4.0])]);
+
+        let result: Tensor<Tensor<f64, 4, 1>, 4, 2> = Tensor([
+            Tensor([0.0, 0.0, 1.0, 2.0]),
+            Tensor([0.0, 0.0, 3.0, 4.0]),
+            Tensor([1.0, 2.0, 0.0, 0.0]),
+            Tensor([3.0, 4.0, 0.0, 0.0]),
+        ]);
+
+        assert_eq!(t1.tensor_mul(t2), result);
+    }
+}
@@ -1,5 +1,6 @@
-use crate::tensor::Tensor;
+use crate::{scalar::Scalar, tensor::Tensor};
 
+/// Conjugate transpose (Hermitian adjoint). For real leaves this is a plain transpose.
 pub trait Transposable {
     type Output: Clone + Transposable;
 
@@ -7,11 +8,14 @@ pub trait Transposable {
     fn T(&self) -> Self::Output;
 }
 
-impl Transposable for f64 {
-    type Output = f64;
+impl<S> Transposable for S
+where
+    S: Scalar,
+{
+    type Output = S;
 
     fn T(&self) -> Self::Output {
-        self.clone()
+        self.conj()
     }
 }
 
@@ -59,6 +63,8 @@ where
 
 #[cfg(test)]
 mod tests {
+    use num_complex::Complex64;
+
     use super::*;
 
     #[test]
@@ -80,4 +86,28 @@ mod tests {
 
         assert_eq!(t1.T(), t2);
     }
+
+    #[test]
+    fn test_f32_tensor_n2_r2_transpose() {
+        let t1: Tensor<Tensor<f32, 2, 1>, 2, 2> = Tensor([Tensor([1.0, 2.0]), Tensor([3.0, 4.0])]);
+
+        let t2: Tensor<Tensor<f32, 2, 1>, 2, 2> = Tensor([Tensor([1.0, 3.0]), Tensor([2.0, 4.0])]);
+
+        assert_eq!(t1.T(), t2);
+    }
+
+    #[test]
+    fn test_complex_tensor_n2_r2_adjoint() {
+        let t1: Tensor<Tensor<Complex64, 2, 1>, 2, 2> = Tensor([
+            Tensor([Complex64::new(1.0, 1.0), Complex64::new(2.0, 0.0)]),
+            Tensor([Complex64::new(0.0, -3.0), Complex64::new(4.0, 5.0)]),
+        ]);
+
+        let t2: Tensor<Tensor<Complex64, 2, 1>, 2, 2> = Tensor([
+            Tensor([Complex64::new(1.0, -1.0), Complex64::new(0.0, 3.0)]),
+            Tensor([Complex64::new(2.0, 0.0), Complex64::new(4.0, -5.0)]),
+        ]);
+
+        assert_eq!(t1.T(), t2);
+    }
 }
@@ -0,0 +1,66 @@
+/// Concatenates `N` arrays of length `M` into a single array of length `N * M`.
+pub fn flatten_arrays<T, const N: usize, const M: usize>(arrays: [[T; M]; N]) -> [T; N * M] {
+    let mut it = arrays.into_iter().flatten();
+
+    [(); N * M].map(|_| it.next().unwrap())
+}
+
+/// Index offset of every joint state of `qubits` within a `num_qubits` state
+/// vector, with `qubits[0]` the most significant bit of the joint state.
+pub fn subsystem_offsets(qubits: &[usize], num_qubits: usize) -> Vec<usize> {
+    assert!(
+        qubits.iter().all(|&q| q < num_qubits),
+        "qubit index out of range for {} qubits: {:?}",
+        num_qubits,
+        qubits
+    );
+    assert!(
+        qubits
+            .iter()
+            .enumerate()
+            .all(|(i, q)| !qubits[..i].contains(q)),
+        "duplicate qubit index: {:?}",
+        qubits
+    );
+
+    let masks: Vec<usize> = qubits.iter().map(|q| 1 << (num_qubits - 1 - q)).collect();
+
+    (0..1 << qubits.len())
+        .map(|sub| {
+            masks
+                .iter()
+                .enumerate()
+                .filter(|(k, _)| (sub >> (qubits.len() - 1 - k)) & 1 == 1)
+                .map(|(_, m)| m)
+                .sum()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatten_arrays() {
+        assert_eq!(flatten_arrays([[1, 2, 3], [4, 5, 6]]), [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_subsystem_offsets() {
+        assert_eq!(subsystem_offsets(&[2, 0], 3), [0b000, 0b100, 0b001, 0b101]);
+        assert_eq!(subsystem_offsets(&[], 3), [0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate qubit index")]
+    fn test_subsystem_offsets_duplicate() {
+        subsystem_offsets(&[0, 0], 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_subsystem_offsets_out_of_range() {
+        subsystem_offsets(&[2], 2);
+    }
+}